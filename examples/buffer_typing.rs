@@ -10,10 +10,7 @@ impl RawMode {
         if status.success() {
             Ok(Self)
         } else {
-            Err(io::Error::new(
-                io::ErrorKind::Other,
-                "failed to enable terminal raw mode",
-            ))
+            Err(io::Error::other("failed to enable terminal raw mode"))
         }
     }
 }
@@ -35,10 +32,11 @@ fn render(committed: &str, composing: &str, last: &str) -> io::Result<()> {
 fn main() -> io::Result<()> {
     let mut stdin = io::stdin().lock();
     let mut buffer = Buffer::default();
+    buffer.set_pass_through(true);
     let mut committed = String::new();
 
     println!("Sejong buffer typing demo");
-    println!("Type QWERTY Korean input. Enter commits, Backspace pops, Space commits a space.");
+    println!("Type QWERTY Korean input. Enter commits, Backspace pops.");
     println!("Press Ctrl-C, Ctrl-D, or Esc to quit.\n");
 
     {
@@ -53,11 +51,6 @@ fn main() -> io::Result<()> {
                     committed.push_str(&buffer.out());
                     "commit".to_string()
                 }
-                b' ' => {
-                    committed.push_str(&buffer.out());
-                    committed.push(' ');
-                    "space".to_string()
-                }
                 8 | 127 => match buffer.pop() {
                     Some(()) => "pop".to_string(),
                     None => {
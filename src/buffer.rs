@@ -1,30 +1,541 @@
-use crate::byte::Byte;
-use crate::syllable::Syllable;
-use std::convert::TryInto;
+use crate::journal::Journal;
+use crate::layout::{Dubeolsik, Layout};
+use crate::syllable::{Jamo, Rules, Syllable};
+use std::convert::TryFrom;
+use std::fmt;
 
 const DEFAULT_BUFFER_CAP: usize = 100;
+const DEFAULT_HISTORY_CAP: usize = 100;
 
-/// It is simply a vector of `Syllable`(private struct).
+/// How [`Buffer`] writes out its syllables.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Output {
+    /// Hangul syllables(U+AC00 to U+D7A3). Jamo that don't make a syllable
+    /// are written as Hangul Compatibility Jamo(U+3131 to U+318E). Syllables
+    /// with archaic Jamo of Old Hangul have no precomposed character, so they
+    /// are written as in `Conjoining`, the way NFC leaves them.
+    #[default]
+    Precomposed,
+    /// Hangul Jamo(conjoining)(U+1100 to U+11FF), as decomposed by NFD. Jamo
+    /// that don't make a syllable get the fillers U+115F and U+1160, so they
+    /// don't join the next syllable. See [`to_conjoining`](crate::to_conjoining).
+    Conjoining,
+}
+
+/// What [`Buffer::pop`] removes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PopMode {
+    /// The last Jamo of the syllable before the cursor. After "은즈" is typed,
+    /// popping leaves "은ㅈ".
+    #[default]
+    Jamo,
+    /// The last keystroke, leaving exactly what was there after the keystroke
+    /// before it, like the Korean IMEs of most operating systems. After "은즈"
+    /// is typed, popping leaves "읁".
+    Keystroke,
+}
+
+/// What happened to the buffer after [`Buffer::put_event`] or
+/// [`Buffer::pop_event`], for input method frameworks that show the syllable
+/// being composed as preedit text.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Event {
+    /// These syllables are finished. They will not be changed by putting
+    /// more keys. They are written as set by [`Buffer::set_output`].
+    Commit(String),
+    /// The syllable being composed. It is a precomposed character whatever
+    /// the output is, as it is only shown until it is committed.
+    Preedit(char),
+    /// The syllable being composed, when it has archaic Jamo of Old Hangul
+    /// and so no precomposed character. It is in Hangul Jamo(conjoining).
+    PreeditJamo(String),
+    /// The key doesn't type any Jamo in the layout. The buffer is unchanged.
+    Rejected(u8),
+    /// One syllable is removed. When there are several, the preedit is
+    /// removed first, and then the committed syllables before it, from the
+    /// last one.
+    Deleted,
+}
+
+/// It is simply a vector of `Syllable`(private struct) and the [`Layout`]
+/// used to read the input. The layout defaults to [`Dubeolsik`].
+///
+/// The buffer has a cursor between syllables, at the end unless it is moved.
+/// Input is put at the cursor, and only the syllable right before the cursor
+/// is composed. Moving the cursor finishes that syllable.
+///
+/// Changes made by [`Buffer::put`], [`Buffer::pop`], [`Buffer::delete`] and
+/// [`Buffer::out`] can be undone and redone.
+///
+/// In streaming mode, finished syllables are taken out of the buffer as soon
+/// as a put finishes them, and wait for [`Buffer::drain`].
 /// See its methods to find examples.
 #[derive(Clone)]
-pub struct Buffer(Vec<Syllable>);
+pub struct Buffer<L = Dubeolsik> {
+    layout: L,
+    output: Output,
+    syllables: Vec<Syllable>,
+    cursor: usize,
+    composing: bool,
+    composed: Vec<Jamo>,
+    journal: Journal<Edit>,
+    pop_mode: PopMode,
+    keystrokes: Vec<Keystroke>,
+    streaming: bool,
+    finished: Vec<Syllable>,
+    pass_through: bool,
+    shift_fallback: bool,
+    rules: Rules,
+}
+
+// What a put changed: the syllable before the cursor, and maybe a new
+// syllable inserted at the cursor.
+#[derive(Clone, Copy)]
+struct Keystroke {
+    cursor: usize,
+    before: Option<Syllable>,
+    composing: bool,
+}
+
+// What a change replaced: the syllables from `start` up to the last `kept`
+// ones, and where the cursor was before and after it.
+#[derive(Clone)]
+struct Edit {
+    start: usize,
+    kept: usize,
+    removed: Vec<Syllable>,
+    inserted: Vec<Syllable>,
+    cursor: (usize, usize),
+    composing: (bool, bool),
+}
 
 impl Buffer {
     /// Initialize the buffer with a capacity. Remember the capacity is for 
     /// `Syllable`(private struct).
     /// It is a capacity for input like `u8` and `char`.
     pub fn with_capacity(cap: usize) -> Self {
-        Self(Vec::with_capacity(cap))
+        Self::with_layout_and_capacity(Dubeolsik, cap)
+    }
+}
+
+impl<L: Layout> Buffer<L> {
+    /// Initialize the buffer with a [`Layout`] other than the default [`Dubeolsik`].
+    pub fn with_layout(layout: L) -> Self {
+        Self::with_layout_and_capacity(layout, DEFAULT_BUFFER_CAP)
+    }
+
+    /// Initialize the buffer with a [`Layout`] and a capacity.
+    /// See [`Buffer::with_capacity`] for the meaning of the capacity.
+    pub fn with_layout_and_capacity(layout: L, cap: usize) -> Self {
+        Self {
+            layout,
+            output: Output::default(),
+            syllables: Vec::with_capacity(cap),
+            cursor: 0,
+            composing: true,
+            composed: Vec::new(),
+            journal: Journal::with_cap(DEFAULT_HISTORY_CAP),
+            pop_mode: PopMode::default(),
+            keystrokes: Vec::new(),
+            streaming: false,
+            finished: Vec::new(),
+            pass_through: false,
+            shift_fallback: false,
+            rules: Rules::default(),
+        }
+    }
+
+    /// Returns the [`Layout`] used by the buffer.
+    pub fn layout(&self) -> &L {
+        &self.layout
+    }
+
+    /// Returns how the buffer writes out its syllables.
+    pub fn output(&self) -> Output {
+        self.output
+    }
+
+    /// Changes how the buffer writes out its syllables in [`Buffer::out`] and
+    /// `to_string()`. The syllables in the buffer are not affected.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::{Buffer, Output};
+    /// let mut buf = Buffer::default();
+    /// buf.put('g');
+    /// buf.put('k');
+    /// buf.put('s');
+    /// buf.put('r');
+    /// buf.set_output(Output::Conjoining);
+    /// assert_eq!(buf.to_string(), "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1160}");
+    /// buf.set_output(Output::Precomposed);
+    /// assert_eq!(buf.to_string(), "한ㄱ");
+    /// ```
+    pub fn set_output(&mut self, output: Output) {
+        self.output = output;
+    }
+
+    /// Returns what [`Buffer::pop`] removes.
+    pub fn pop_mode(&self) -> PopMode {
+        self.pop_mode
+    }
+
+    /// Changes what [`Buffer::pop`] removes. Only keystrokes put after
+    /// switching to [`PopMode::Keystroke`] can be popped one by one.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::{Buffer, PopMode};
+    /// let mut buf = Buffer::default();
+    /// buf.set_pop_mode(PopMode::Keystroke);
+    /// for key in "dmswm".chars() {
+    ///     buf.put(key);
+    /// }
+    /// assert_eq!(buf.to_string(), "은즈");
+    /// buf.pop();
+    /// assert_eq!(buf.to_string(), "읁");
+    /// buf.pop();
+    /// assert_eq!(buf.to_string(), "은");
+    /// ```
+    pub fn set_pop_mode(&mut self, pop_mode: PopMode) {
+        self.pop_mode = pop_mode;
+        self.keystrokes.clear();
+    }
+
+    /// Returns whether the buffer is in streaming mode.
+    pub fn streaming(&self) -> bool {
+        self.streaming
+    }
+
+    /// Turns streaming mode on or off. In streaming mode, the syllables before
+    /// the cursor that can't be changed by [`Buffer::put`] anymore are moved
+    /// out of the buffer right after each put, so a long input session only
+    /// keeps the syllables still being edited. Take them with
+    /// [`Buffer::drain`]. They can't be popped or undone, but the syllables
+    /// still in the buffer can.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// buf.set_streaming(true);
+    /// for key in "dkssud".chars() {
+    ///     buf.put(key);
+    /// }
+    /// assert_eq!(buf.drain().collect::<String>(), "안");
+    /// assert_eq!(buf.to_string(), "녕");
+    /// buf.put('g');
+    /// assert_eq!(buf.drain().collect::<String>(), "녕");
+    /// assert_eq!(buf.to_string(), "ㅎ");
+    /// ```
+    pub fn set_streaming(&mut self, streaming: bool) {
+        self.streaming = streaming;
+    }
+
+    /// Takes the syllables moved out of the buffer in streaming mode, in the
+    /// order they were typed, written as set by [`Buffer::set_output`].
+    /// What was drained followed by `to_string()` is always the whole text.
+    pub fn drain(&mut self) -> impl Iterator<Item = char> + '_ {
+        let output = self.output;
+        self.finished
+            .drain(..)
+            .flat_map(move |syl| write(syl, output))
+    }
+
+    /// Returns whether the buffer keeps characters its layout doesn't map.
+    pub fn pass_through(&self) -> bool {
+        self.pass_through
+    }
+
+    /// Turns pass-through mode on or off. In pass-through mode,
+    /// [`Buffer::put`] accepts any character. One that the layout doesn't map
+    /// to a Jamo, like a digit, a space or a punctuation mark, finishes the
+    /// syllable being composed and is kept in the buffer as it is, so
+    /// `to_string()` has the whole typed line. It is removed as a whole by
+    /// [`Buffer::pop`].
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// buf.set_pass_through(true);
+    /// for key in "dkssud, 2024!".chars() {
+    ///     assert!(buf.put(key).is_none());
+    /// }
+    /// assert_eq!(buf.to_string(), "안녕, 2024!");
+    /// ```
+    pub fn set_pass_through(&mut self, pass_through: bool) {
+        self.pass_through = pass_through;
+    }
+
+    /// Returns whether keys the layout doesn't map are read as the keys the
+    /// layout gives for them without Shift.
+    pub fn shift_fallback(&self) -> bool {
+        self.shift_fallback
+    }
+
+    /// Turns the shift fallback on or off. It is off by default, and an
+    /// uppercase letter is rejected unless the layout maps it, like 'Q' to 'ㅃ'
+    /// in [`Dubeolsik`]. When it is on, a key the layout doesn't map types what
+    /// [`Layout::unshifted`] gives for it. In [`Dubeolsik`] that is the
+    /// lowercase letter, as Shift does in the Korean IMEs of most operating
+    /// systems. Other layouts have no fallback unless they provide one.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// assert_eq!(buf.put('A'), Some('A'));
+    /// buf.set_shift_fallback(true);
+    /// for key in "GKS".chars() {
+    ///     assert!(buf.put(key).is_none());
+    /// }
+    /// assert_eq!(buf.to_string(), "한");
+    /// buf.put('R');
+    /// assert_eq!(buf.to_string(), "한ㄲ");
+    /// ```
+    pub fn set_shift_fallback(&mut self, shift_fallback: bool) {
+        self.shift_fallback = shift_fallback;
+    }
+
+    /// Returns whether Jamo that don't make a syllable combine into compound
+    /// Jamo.
+    pub fn compound_jamo(&self) -> bool {
+        self.rules.compound_jamo
+    }
+
+    /// Turns compound Jamo on or off. It is off by default, and 'ㄱ' followed
+    /// by 'ㅅ' stays "ㄱㅅ". When it is on, consonants and vowels that don't
+    /// make a syllable combine as they would in one, into "ㄳ" or "ㅘ". A
+    /// vowel after a compound consonant takes its second half.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// buf.set_compound_jamo(true);
+    /// buf.put('r');
+    /// buf.put('t');
+    /// assert_eq!(buf.to_string(), "ㄳ");
+    /// buf.put('k');
+    /// assert_eq!(buf.to_string(), "ㄱ사");
+    /// buf.put('h');
+    /// buf.put('k');
+    /// assert_eq!(buf.to_string(), "ㄱ사ㅘ");
+    /// ```
+    pub fn set_compound_jamo(&mut self, compound_jamo: bool) {
+        self.rules.compound_jamo = compound_jamo;
+        self.composed.clear();
+    }
+
+    /// Returns whether a consonant typed twice becomes its tense consonant.
+    pub fn double_tap(&self) -> bool {
+        self.rules.double_tap
+    }
+
+    /// Turns double-tap tense consonants on or off, for keyboards where Shift
+    /// is hard to reach. When it is on, 'ㄱ', 'ㄷ', 'ㅂ', 'ㅅ' and 'ㅈ' typed
+    /// twice as an initial consonant become 'ㄲ', 'ㄸ', 'ㅃ', 'ㅆ' and 'ㅉ'.
+    /// 'ㄱ' and 'ㅅ' typed twice as a final consonant become 'ㄲ' and 'ㅆ'.
+    /// [`Buffer::pop`] takes back the second tap, leaving the plain consonant.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// buf.set_double_tap(true);
+    /// for key in "eekrkrr".chars() {
+    ///     buf.put(key);
+    /// }
+    /// assert_eq!(buf.to_string(), "따갂");
+    /// buf.put('k');
+    /// assert_eq!(buf.to_string(), "따가까");
+    /// ```
+    pub fn set_double_tap(&mut self, double_tap: bool) {
+        self.rules.double_tap = double_tap;
+        self.composed.clear();
+    }
+
+    /// Returns whether a vowel can be typed before its initial consonant.
+    pub fn moachigi(&self) -> bool {
+        self.rules.moachigi
+    }
+
+    /// Turns moachigi on or off. It is off by default, and 'ㅏ' followed by
+    /// 'ㄱ' stays "ㅏㄱ". When it is on, a consonant typed right after a vowel
+    /// on its own becomes the initial consonant of that vowel, so 'ㅏ' and 'ㄱ'
+    /// make "가" in either order. It only applies to a vowel that is still
+    /// being composed, and only when it has no initial consonant yet. The
+    /// syllable then goes on as usual, so the next consonant is its final.
+    /// [`Buffer::pop`] removes the Jamo in the order they were typed, so the
+    /// consonant goes first and the vowel is left on its own.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// buf.set_moachigi(true);
+    /// for key in "kdsrk".chars() {
+    ///     buf.put(key);
+    /// }
+    /// assert_eq!(buf.to_string(), "안가");
+    /// buf.put('k');
+    /// buf.put('r');
+    /// assert_eq!(buf.to_string(), "안가가");
+    /// ```
+    pub fn set_moachigi(&mut self, moachigi: bool) {
+        self.rules.moachigi = moachigi;
+        self.composed.clear();
+    }
+
+    /// Returns whether consonants typed at the start of a syllable make an
+    /// initial consonant cluster of Old Hangul.
+    pub fn initial_clusters(&self) -> bool {
+        self.rules.initial_clusters
+    }
+
+    /// Turns initial consonant clusters on or off. It is off by default, and
+    /// 'ㅂ' followed by 'ㅅ' stays "ㅂㅅ". When it is on, consonants typed one
+    /// after another at the start of a syllable make the clusters of Old
+    /// Hangul(옛한글), like 'ㅂ' and 'ㅅ' into 'ㅄ', then 'ㄱ' into 'ㅴ'.
+    /// [`Buffer::pop`] takes the consonants back one by one. Such syllables
+    /// have no precomposed character, see [`Output::Precomposed`].
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// buf.set_initial_clusters(true);
+    /// for key in "qtrk".chars() {
+    ///     buf.put(key);
+    /// }
+    /// assert_eq!(buf.to_string(), "\u{1122}\u{1161}");
+    /// ```
+    pub fn set_initial_clusters(&mut self, initial_clusters: bool) {
+        self.rules.initial_clusters = initial_clusters;
+        self.composed.clear();
+    }
+
+    /// Returns how many changes can be undone at most.
+    pub fn history_cap(&self) -> usize {
+        self.journal.cap()
+    }
+
+    /// Changes how many changes can be undone at most. It is 100 by default.
+    /// Setting it to 0 turns undo and redo off.
+    pub fn set_history_cap(&mut self, cap: usize) {
+        self.journal.set_cap(cap);
+    }
+
+    /// Reverts the last change made by [`Buffer::put`], [`Buffer::pop`],
+    /// [`Buffer::delete`] or [`Buffer::out`]. Returns `None` when there is
+    /// nothing to undo.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// for key in "dks".chars() {
+    ///     buf.put(key);
+    /// }
+    /// assert_eq!(buf.out(), "안");
+    /// assert!(buf.undo().is_some());
+    /// assert_eq!(buf.to_string(), "안");
+    /// assert!(buf.undo().is_some());
+    /// assert_eq!(buf.to_string(), "아");
+    /// assert!(buf.redo().is_some());
+    /// assert_eq!(buf.to_string(), "안");
+    /// ```
+    pub fn undo(&mut self) -> Option<()> {
+        let edit = self.journal.undo()?;
+        let end = edit.start + edit.inserted.len();
+        self.syllables
+            .splice(edit.start..end, edit.removed.iter().copied());
+        self.cursor = edit.cursor.0;
+        self.composing = edit.composing.0;
+        self.composed.clear();
+        self.keystrokes.clear();
+        Some(())
     }
 
-    /// Put a byte('u8') or a 'char' into the buffer. A valid byte_candidate should be
-    /// meets all of the following requirements:
-    ///     1. a valid English letter in ASCII chart.
-    ///     2. this English letter must has a corresponding valid modern Hangul Jamo as
-    /// in a standard Korean 2-set(QWERT) keyboard.
+    /// Puts back the last change reverted by [`Buffer::undo`]. Returns `None`
+    /// when there is nothing to redo. Any new change drops what can be redone.
+    pub fn redo(&mut self) -> Option<()> {
+        let edit = self.journal.redo()?;
+        let end = edit.start + edit.removed.len();
+        self.syllables
+            .splice(edit.start..end, edit.inserted.iter().copied());
+        self.cursor = edit.cursor.1;
+        self.composing = edit.composing.1;
+        self.composed.clear();
+        self.keystrokes.clear();
+        Some(())
+    }
+
+    /// Returns the position of the cursor, counted in syllables from the start.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to `position`, counted in syllables from the start.
+    /// Returns `None` and keeps the cursor when `position` is out of the buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// for key in "dkssud".chars() {
+    ///     buf.put(key);
+    /// }
+    /// assert!(buf.set_cursor(0).is_some());
+    /// buf.put('g');
+    /// buf.put('k');
+    /// assert_eq!(buf.to_string(), "하안녕");
+    /// assert!(buf.set_cursor(4).is_none());
+    /// ```
+    pub fn set_cursor(&mut self, position: usize) -> Option<()> {
+        if position > self.syllables.len() {
+            return None;
+        }
+        self.cursor = position;
+        self.composing = false;
+        self.composed.clear();
+        self.keystrokes.clear();
+        Some(())
+    }
+
+    /// Moves the cursor one syllable to the left. Returns `None` when the cursor
+    /// is already at the start.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Buffer;
+    /// let mut buf = Buffer::default();
+    /// for key in "rksk".chars() {
+    ///     buf.put(key);
+    /// }
+    /// assert!(buf.move_left().is_some());
+    /// buf.put('s');
+    /// assert_eq!(buf.to_string(), "가ㄴ나");
+    /// ```
+    pub fn move_left(&mut self) -> Option<()> {
+        self.set_cursor(self.cursor.checked_sub(1)?)
+    }
+
+    /// Moves the cursor one syllable to the right. Returns `None` when the cursor
+    /// is already at the end.
+    pub fn move_right(&mut self) -> Option<()> {
+        self.set_cursor(self.cursor + 1)
+    }
+
+    /// Put a byte('u8') or a 'char' into the buffer. A valid byte_candidate
+    /// must be a key that has a corresponding valid modern Hangul Jamo in the
+    /// buffer's [`Layout`]. With the default [`Dubeolsik`], that is an English
+    /// letter as in a standard Korean 2-set(QWERT) keyboard.
     /// When a byte_candidate is accepted by the buffer, this will return None.
     /// When a byte_candidate can't be matched with a valid modern Hangul Jamo, this
-    /// will return `Some(byte_candidate)`.
+    /// will return `Some(byte_candidate)`, unless the buffer is in pass-through
+    /// mode. See [`Buffer::set_pass_through`].
     ///
     /// # Example
     /// ```
@@ -37,19 +548,20 @@ impl Buffer {
     /// ```
     pub fn put<T>(&mut self, byte_candidate: T) -> Option<T>
     where
-        T: TryInto<Byte, Error = T>,
+        T: Copy + Into<char>,
     {
-        match byte_candidate.try_into() {
-            Ok(byte) => {
-                self.put_byte(byte);
-                None
-            }
-            Err(returned) => Some(returned),
+        if !self.put_key(byte_candidate.into()) {
+            return Some(byte_candidate);
         }
+        self.stream();
+        None
     }
 
     /// Removes the last single Jamo put. Returns `Some(())` when it succeeds.
     /// Returns `None` when it fails. It fails when buffer is empty.
+    /// When the cursor has been moved, this removes the whole syllable
+    /// before the cursor instead, and fails when the cursor is at the start.
+    /// See [`PopMode`] to remove the last keystroke instead of the last Jamo.
     ///
     /// # Example
     /// ```
@@ -67,41 +579,157 @@ impl Buffer {
     /// assert!(buf.pop().is_none());
     /// ```
     pub fn pop(&mut self) -> Option<()> {
-        if let Some(last) = self.0.last_mut() {
-            last.remove_last().or_else(|| self.0.pop().and(Some(())))
-        } else {
-            None
+        let start = self.pop_start()?;
+        let before = self.cursor - 1;
+        let edit = self.begin_edit(start, self.cursor);
+        if let Some(keystroke) = self.keystrokes.pop() {
+            self.syllables.drain(keystroke.cursor..self.cursor);
+            if let Some(syl) = keystroke.before {
+                self.syllables[keystroke.cursor - 1] = syl;
+            }
+            self.cursor = keystroke.cursor;
+            self.composing = keystroke.composing;
+            self.composed.clear();
+        } else if !self.composing || self.remove_last_jamo().is_none() {
+            self.syllables.remove(before);
+            self.cursor = before;
         }
+        self.end_edit(edit);
+        Some(())
     }
 
-    /// Output the buffer as a UTF-32 string. Calling this method clears the buffer.
-    /// If buffer needs to be preserved, use [`Buffer::to_string`].
+    /// Removes the whole syllable after the cursor. Returns `None` when the
+    /// cursor is at the end. This finishes the syllable before the cursor.
     ///
     /// # Example
     /// ```
-    /// use sejong::{Buffer, Byte};
+    /// use sejong::Buffer;
     /// let mut buf = Buffer::default();
-    /// buf.put(Byte::NG as u8);
-    /// buf.put(Byte::A as u8);
-    /// buf.put(Byte::N as u8);
-    /// assert_eq!(buf.out(), "안");
+    /// for key in "dkssud".chars() {
+    ///     buf.put(key);
+    /// }
+    /// buf.set_cursor(0);
+    /// assert!(buf.delete().is_some());
+    /// assert_eq!(buf.to_string(), "녕");
+    /// ```
+    pub fn delete(&mut self) -> Option<()> {
+        if self.cursor == self.syllables.len() {
+            return None;
+        }
+        let edit = self.begin_edit(self.cursor, self.cursor + 1);
+        self.syllables.remove(self.cursor);
+        self.composing = false;
+        self.composed.clear();
+        self.keystrokes.clear();
+        self.end_edit(edit);
+        Some(())
+    }
+
+    /// Works like [`Buffer::put`], but tells what happened as [`Event`]s.
+    /// An accepted key gives the syllables it finished, if any, as
+    /// [`Event::Commit`], and then the syllable being composed as
+    /// [`Event::Preedit`]. A character kept in pass-through mode is committed
+    /// right away, with no preedit after it.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::{Buffer, Event};
+    /// let mut buf = Buffer::default();
+    /// assert_eq!(buf.put_event(b'r'), vec![Event::Preedit('ㄱ')]);
+    /// assert_eq!(buf.put_event(b'k'), vec![Event::Preedit('가')]);
+    /// assert_eq!(buf.put_event(b's'), vec![Event::Preedit('간')]);
+    /// assert_eq!(
+    ///     buf.put_event(b'k'),
+    ///     vec![Event::Commit("가".to_string()), Event::Preedit('나')]
+    /// );
+    /// assert_eq!(buf.put_event(b'1'), vec![Event::Rejected(b'1')]);
+    /// assert_eq!(buf.to_string(), "가나");
+    /// ```
+    pub fn put_event(&mut self, key: u8) -> Vec<Event> {
+        let first_changed = self.committed();
+        if !self.put_key(key.into()) {
+            return vec![Event::Rejected(key)];
+        }
+        let preedit = self.committed();
+        let mut events = Vec::with_capacity(2);
+        if first_changed < preedit {
+            let committed = self.syllables[first_changed..preedit]
+                .iter()
+                .flat_map(|syl| write(*syl, self.output))
+                .collect();
+            events.push(Event::Commit(committed));
+        }
+        if self.composing {
+            events.push(preedit_event(self.syllables[preedit]));
+        }
+        self.stream();
+        events
+    }
+
+    /// Works like [`Buffer::pop`], but tells what happened as [`Event`]s.
+    /// When the preedit is gone and the syllable before it is composed again,
+    /// that syllable is taken back from the committed text: it's
+    /// [`Event::Deleted`] and shown as preedit again.
+    /// Returns no event when the cursor is at the start.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::{Buffer, Event};
+    /// let mut buf = Buffer::default();
+    /// for key in "rksk".bytes() {
+    ///     buf.put_event(key);
+    /// }
+    /// assert_eq!(buf.pop_event(), vec![Event::Preedit('ㄴ')]);
+    /// assert_eq!(
+    ///     buf.pop_event(),
+    ///     vec![Event::Deleted, Event::Deleted, Event::Preedit('가')]
+    /// );
+    /// assert_eq!(buf.pop_event(), vec![Event::Preedit('ㄱ')]);
+    /// assert_eq!(buf.pop_event(), vec![Event::Deleted]);
     /// assert_eq!(buf.to_string(), "");
+    /// assert_eq!(buf.pop_event(), vec![]);
     /// ```
-    pub fn out(&mut self) -> String {
-        let mut result = String::with_capacity(self.0.len());
-        self.0.reverse();
-        loop {
-            match self.0.pop() {
-                Some(syl) => result.push(syl.into()),
-                None => break,
+    pub fn pop_event(&mut self) -> Vec<Event> {
+        let start = match self.pop_start() {
+            Some(start) => start,
+            None => return Vec::new(),
+        };
+        let had_preedit = self.composing;
+        let committed_before = self.committed();
+        self.pop();
+        let committed_after = self.committed();
+        let kept = start.min(committed_before).min(committed_after);
+        let preedit = match self.composing {
+            true => self
+                .syllables
+                .get(committed_after)
+                .map(|syl| preedit_event(*syl)),
+            false => None,
+        };
+        if had_preedit && kept == committed_before && kept == committed_after {
+            if let Some(preedit) = preedit {
+                return vec![preedit];
             }
         }
-        result
+        let mut events = Vec::new();
+        if had_preedit {
+            events.push(Event::Deleted);
+        }
+        events.extend((kept..committed_before).map(|_| Event::Deleted));
+        if kept < committed_after {
+            let committed = self.syllables[kept..committed_after]
+                .iter()
+                .flat_map(|syl| write(*syl, self.output))
+                .collect();
+            events.push(Event::Commit(committed));
+        }
+        events.extend(preedit);
+        events
     }
 
-    /// Output the buffer as a UTF-32 string. It is very similar with [`Buffer::out`].
-    /// But `to_string()` doesn't clear the buffer. It always reflect the
-    /// current state of the buffer.
+    /// Output the buffer as a UTF-32 string. Calling this method clears the buffer.
+    /// If buffer needs to be preserved, use `to_string()`.
+    /// In streaming mode, the syllables not drained yet come first.
     ///
     /// # Example
     /// ```
@@ -110,36 +738,287 @@ impl Buffer {
     /// buf.put(Byte::NG as u8);
     /// buf.put(Byte::A as u8);
     /// buf.put(Byte::N as u8);
-    /// assert_eq!(buf.to_string(), "안");
-    /// assert_eq!(buf.to_string(), "안");
+    /// assert_eq!(buf.out(), "안");
+    /// assert_eq!(buf.to_string(), "");
     /// ```
-    pub fn to_string(&self) -> String {
-        let mut result = String::with_capacity(self.0.len());
-        for syl in &self.0 {
-            result.push((*syl).into());
+    pub fn out(&mut self) -> String {
+        let mut result: String = self.drain().collect();
+        result.push_str(&self.to_string());
+        if !self.syllables.is_empty() {
+            let edit = self.begin_edit(0, self.syllables.len());
+            self.syllables.clear();
+            self.cursor = 0;
+            self.composing = true;
+            self.end_edit(edit);
         }
+        self.composed.clear();
+        self.keystrokes.clear();
         result
     }
 
-    fn put_byte(&mut self, b: Byte) {
-        if let Some(last) = self.0.last_mut() {
-            if let Some(b) = last.put(b) {
-                if let Ok(new_syl) = last.try_split_with_vowel(b) {
-                    self.0.push(new_syl);
+    // Puts the Jamo the layout maps `key` to, or `key` itself in pass-through
+    // mode. Returns false when it is rejected.
+    fn put_key(&mut self, key: char) -> bool {
+        let jamo = match self.layout.map(key) {
+            None if self.shift_fallback => self
+                .layout
+                .unshifted(key)
+                .and_then(|key| self.layout.map(key)),
+            jamo => jamo,
+        };
+        if jamo.is_none() && !self.pass_through {
+            return false;
+        }
+        match jamo {
+            Some(jamo) => self.record_put(|buf| buf.compose(jamo)),
+            None => self.record_put(|buf| buf.insert_literal(key)),
+        }
+        true
+    }
+
+    // Puts a Jamo read by an input method other than the layout. It is not
+    // recorded in the history, as such a buffer is composed again from the
+    // input every time it is shown.
+    pub(crate) fn put_jamo(&mut self, jamo: Jamo) {
+        self.compose(jamo);
+    }
+
+    // Puts a character that is kept as it is, like in pass-through mode. It
+    // is not recorded in the history either.
+    pub(crate) fn put_literal(&mut self, c: char) {
+        self.insert_literal(c);
+    }
+
+    // Finishes the syllable being composed, so the next Jamo starts a new one.
+    pub(crate) fn finish(&mut self) {
+        self.composing = false;
+        self.composed.clear();
+    }
+
+    // A put only changes the syllable before the cursor and inserts after it.
+    fn record_put(&mut self, put: impl FnOnce(&mut Self)) {
+        let edit = self.begin_edit(self.cursor.saturating_sub(1), self.cursor);
+        if self.pop_mode == PopMode::Keystroke {
+            self.keystrokes.push(Keystroke {
+                cursor: self.cursor,
+                before: self.cursor.checked_sub(1).map(|i| self.syllables[i]),
+                composing: self.composing,
+            });
+        }
+        put(self);
+        self.end_edit(edit);
+    }
+
+    // The syllables before the cursor, without the one being composed.
+    fn committed(&self) -> usize {
+        match self.composing {
+            true => self.cursor.saturating_sub(1),
+            false => self.cursor,
+        }
+    }
+
+    // The first syllable `pop` changes: the one before the cursor, or the one
+    // before the last keystroke in PopMode::Keystroke.
+    fn pop_start(&self) -> Option<usize> {
+        let before = self.cursor.checked_sub(1)?;
+        Some(match self.keystrokes.last() {
+            Some(keystroke) => keystroke.cursor.saturating_sub(1),
+            None => before,
+        })
+    }
+
+    // Moves the syllables before the composing one out of the buffer, when
+    // streaming. The history is moved along with the rest of the syllables.
+    // The last change that reached into the moved syllables is cut down to
+    // the syllables left, and everything before it is dropped.
+    fn stream(&mut self) {
+        let finished = self.committed();
+        if !self.streaming || finished == 0 {
+            return;
+        }
+        self.finished.extend(self.syllables.drain(..finished));
+        self.cursor -= finished;
+
+        let mut cut = false;
+        self.journal.retain_recent(|edit| {
+            if cut {
+                return false;
+            }
+            if edit.start < finished {
+                let moved = finished - edit.start;
+                if moved >= edit.inserted.len() {
+                    return false;
+                }
+                cut = true;
+                edit.inserted.drain(..moved);
+                edit.removed.drain(..moved.min(edit.removed.len()));
+                edit.start = finished;
+            }
+            edit.start -= finished;
+            edit.cursor.0 = edit.cursor.0.saturating_sub(finished);
+            edit.cursor.1 -= finished;
+            true
+        });
+
+        let mut kept = 0;
+        for keystroke in self.keystrokes.iter_mut().rev() {
+            if keystroke.cursor < finished {
+                break;
+            }
+            kept += 1;
+            if keystroke.cursor == finished {
+                // It can still take back what it put after the moved syllables.
+                keystroke.cursor = 0;
+                keystroke.before = None;
+                break;
+            }
+            keystroke.cursor -= finished;
+        }
+        self.keystrokes.drain(..self.keystrokes.len() - kept);
+    }
+
+    fn compose(&mut self, jamo: Jamo) {
+        let rules = self.rules;
+        if let Some(before) = self.composing_syllable() {
+            if let Some(jamo) = before.put(jamo, rules) {
+                if let Ok(new_syl) = before.try_split_with_vowel(jamo) {
+                    self.insert(new_syl);
+                    self.composed.clear();
+                    if let Syllable::Medial(ic, _) = new_syl {
+                        self.composed.push(Jamo::Consonant(ic));
+                    }
+                    self.composed.push(jamo);
                     return;
                 }
             } else {
+                // Unless the Jamo typed since the syllable started are all
+                // known, `remove_last_jamo` can't compose it again from them.
+                if !self.composed.is_empty() {
+                    self.composed.push(jamo);
+                }
                 return;
             }
         }
 
-        self.0.push(b.into());
+        self.insert(jamo.into());
+        self.composed.clear();
+        self.composed.push(jamo);
+    }
+
+    // Removes the last Jamo put into the syllable being composed. When all
+    // the Jamo typed into it are known, the syllable is composed again
+    // without the last one, so a double-tapped 'ㄲ' goes back to 'ㄱ' and a
+    // vowel typed before its consonant is left on its own. They are unknown
+    // when `composed` was cleared while the syllable was being composed, as
+    // by an undo or a change of the rules. Returns `None` when nothing would
+    // be left.
+    fn remove_last_jamo(&mut self) -> Option<()> {
+        let before = self.cursor - 1;
+        if self.composed.is_empty() {
+            return self.syllables[before].remove_last();
+        }
+        self.composed.pop();
+        let (first, rest) = self.composed.split_first()?;
+        let mut syl = Syllable::from(*first);
+        for jamo in rest {
+            syl.put(*jamo, self.rules);
+        }
+        self.syllables[before] = syl;
+        Some(())
+    }
+
+    // Starts recording a change to the syllables from `start` to `end`. Those
+    // after `end` must be left as they are.
+    fn begin_edit(&self, start: usize, end: usize) -> Edit {
+        Edit {
+            start,
+            kept: self.syllables.len() - end,
+            removed: self.syllables[start..end].to_vec(),
+            inserted: Vec::new(),
+            cursor: (self.cursor, self.cursor),
+            composing: (self.composing, self.composing),
+        }
+    }
+
+    fn end_edit(&mut self, mut edit: Edit) {
+        if self.journal.cap() == 0 {
+            return;
+        }
+        let end = self.syllables.len() - edit.kept;
+        edit.inserted = self.syllables[edit.start..end].to_vec();
+        edit.cursor.1 = self.cursor;
+        edit.composing.1 = self.composing;
+        self.journal.record(edit);
+    }
+
+    fn composing_syllable(&mut self) -> Option<&mut Syllable> {
+        if !self.composing {
+            return None;
+        }
+        let before = self.cursor.checked_sub(1)?;
+        self.syllables.get_mut(before)
+    }
+
+    fn insert_literal(&mut self, c: char) {
+        self.insert(Syllable::Literal(c));
+        self.composing = false;
+        self.composed.clear();
+    }
+
+    fn insert(&mut self, syl: Syllable) {
+        self.syllables.insert(self.cursor, syl);
+        self.cursor += 1;
+        self.composing = true;
+    }
+}
+
+/// Output the buffer as a UTF-32 string with `to_string()`. It is very similar
+/// with [`Buffer::out`]. But `to_string()` doesn't clear the buffer. It always
+/// reflect the current state of the buffer.
+///
+/// # Example
+/// ```
+/// use sejong::{Buffer, Byte};
+/// let mut buf = Buffer::default();
+/// buf.put(Byte::NG as u8);
+/// buf.put(Byte::A as u8);
+/// buf.put(Byte::N as u8);
+/// assert_eq!(buf.to_string(), "안");
+/// assert_eq!(buf.to_string(), "안");
+/// ```
+impl<L> fmt::Display for Buffer<L> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for syl in &self.syllables {
+            for c in write(*syl, self.output) {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Writes the syllable as set by the output.
+fn write(syl: Syllable, output: Output) -> impl Iterator<Item = char> {
+    let (precomposed, conjoining) = match output {
+        Output::Precomposed => (Some(syl.precomposed()), None),
+        Output::Conjoining => (None, Some(syl.conjoining())),
+    };
+    precomposed
+        .into_iter()
+        .flatten()
+        .chain(conjoining.into_iter().flatten())
+}
+
+fn preedit_event(syl: Syllable) -> Event {
+    match char::try_from(syl) {
+        Ok(c) => Event::Preedit(c),
+        Err(syl) => Event::PreeditJamo(syl.conjoining().collect()),
     }
 }
 
-impl Into<String> for Buffer {
-    fn into(self) -> String {
-        self.to_string()
+impl<L> From<Buffer<L>> for String {
+    fn from(buffer: Buffer<L>) -> Self {
+        buffer.to_string()
     }
 }
 
@@ -152,6 +1031,7 @@ impl Default for Buffer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::byte::Byte;
 
     #[test]
     fn test_buffer() {
@@ -169,7 +1049,7 @@ mod tests {
         buffer.put(Byte::NG as u8);
         buffer.put(Byte::YEO as u8);
 
-        assert_eq!(5, buffer.0.len());
+        assert_eq!(5, buffer.syllables.len());
 
         let word: String = buffer.clone().into();
         assert_eq!("안녕하세여", word);
@@ -264,4 +1144,609 @@ mod tests {
         assert!(buffer.pop().is_some());
         assert_eq!("으", buffer.to_string());
     }
+
+    #[test]
+    fn test_cursor_editing() {
+        let mut buffer = Buffer::default();
+        for key in "dkssudgktpdy".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("안녕하세요", buffer.to_string());
+        assert_eq!(5, buffer.cursor());
+
+        assert!(buffer.move_left().is_some());
+        assert!(buffer.move_left().is_some());
+        assert_eq!(3, buffer.cursor());
+        buffer.put(Byte::S as u8);
+        buffer.put(Byte::I as u8);
+        buffer.put(Byte::NG as u8);
+        assert_eq!("안녕하싱세요", buffer.to_string());
+        assert_eq!(4, buffer.cursor());
+
+        assert!(buffer.pop().is_some());
+        assert_eq!("안녕하시세요", buffer.to_string());
+        buffer.put(Byte::EO as u8);
+        assert_eq!("안녕하시ㅓ세요", buffer.to_string());
+
+        assert!(buffer.move_left().is_some());
+        assert!(buffer.pop().is_some());
+        assert_eq!("안녕하ㅓ세요", buffer.to_string());
+        assert!(buffer.delete().is_some());
+        assert_eq!("안녕하세요", buffer.to_string());
+        assert_eq!(3, buffer.cursor());
+
+        assert!(buffer.set_cursor(0).is_some());
+        assert!(buffer.move_left().is_none());
+        assert!(buffer.pop().is_none());
+        assert!(buffer.set_cursor(5).is_some());
+        assert!(buffer.move_right().is_none());
+        assert!(buffer.delete().is_none());
+    }
+
+    #[test]
+    fn test_moving_cursor_finishes_syllable() {
+        let mut buffer = Buffer::default();
+        buffer.put(Byte::G as u8);
+        buffer.put(Byte::A as u8);
+        assert!(buffer.move_left().is_some());
+        assert!(buffer.move_right().is_some());
+        buffer.put(Byte::N as u8);
+        assert_eq!("가ㄴ", buffer.to_string());
+
+        assert!(buffer.pop().is_some());
+        assert!(buffer.move_left().is_some());
+        assert!(buffer.move_right().is_some());
+        assert!(buffer.pop().is_some());
+        assert_eq!("", buffer.to_string());
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut buffer = Buffer::default();
+        assert!(buffer.undo().is_none());
+        for key in "dmsw".chars() {
+            buffer.put(key);
+        }
+        buffer.put('1');
+        assert_eq!("읁", buffer.to_string());
+
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_some());
+        assert_eq!("으", buffer.to_string());
+        assert!(buffer.undo().is_some());
+        assert!(buffer.undo().is_some());
+        assert_eq!("읁", buffer.to_string());
+        assert!(buffer.undo().is_some());
+        assert_eq!("은", buffer.to_string());
+
+        assert!(buffer.redo().is_some());
+        assert_eq!("읁", buffer.to_string());
+        buffer.put(Byte::EU as u8);
+        assert_eq!("은즈", buffer.to_string());
+        assert!(buffer.redo().is_none());
+
+        buffer.move_left();
+        assert!(buffer.delete().is_some());
+        assert_eq!("은", buffer.to_string());
+        assert!(buffer.undo().is_some());
+        assert_eq!("은즈", buffer.to_string());
+        assert_eq!(1, buffer.cursor());
+    }
+
+    #[test]
+    fn test_history_cap() {
+        let mut buffer = Buffer::default();
+        buffer.set_history_cap(2);
+        for key in "dkssud".chars() {
+            buffer.put(key);
+        }
+        assert!(buffer.undo().is_some());
+        assert!(buffer.undo().is_some());
+        assert!(buffer.undo().is_none());
+        assert_eq!("안ㄴ", buffer.to_string());
+
+        buffer.set_history_cap(0);
+        buffer.put(Byte::YEO as u8);
+        assert!(buffer.undo().is_none());
+        assert_eq!("안녀", buffer.to_string());
+    }
+
+    #[test]
+    fn test_history_cap_keeps_next_redo() {
+        let mut buffer = Buffer::default();
+        for key in "dkssud".chars() {
+            buffer.put(key);
+        }
+        for _ in 0..5 {
+            buffer.undo();
+        }
+        assert_eq!("ㅇ", buffer.to_string());
+
+        buffer.set_history_cap(2);
+        assert!(buffer.redo().is_some());
+        assert_eq!("아", buffer.to_string());
+        assert!(buffer.redo().is_some());
+        assert_eq!("안", buffer.to_string());
+        assert!(buffer.redo().is_none());
+        assert!(buffer.undo().is_some());
+        assert!(buffer.undo().is_some());
+        assert!(buffer.undo().is_none());
+        assert_eq!("ㅇ", buffer.to_string());
+    }
+
+    #[test]
+    fn test_keystroke_pop() {
+        let mut buffer = Buffer::default();
+        buffer.set_pop_mode(PopMode::Keystroke);
+        for key in "dmswm".chars() {
+            buffer.put(key);
+        }
+        let expected = vec!["읁", "은", "으", "ㅇ", ""];
+        for state in expected {
+            assert!(buffer.pop().is_some());
+            assert_eq!(state, buffer.to_string());
+        }
+        assert!(buffer.pop().is_none());
+
+        for key in "rhkr".chars() {
+            buffer.put(key);
+        }
+        buffer.move_left();
+        buffer.move_right();
+        buffer.put(Byte::A as u8);
+        assert!(buffer.pop().is_some());
+        assert_eq!("곽", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("", buffer.to_string());
+    }
+
+    #[test]
+    fn test_keystroke_pop_in_the_middle() {
+        let mut buffer = Buffer::default();
+        buffer.set_pop_mode(PopMode::Keystroke);
+        for key in "dkssud".chars() {
+            buffer.put(key);
+        }
+        buffer.set_cursor(1);
+        for key in "rks".chars() {
+            buffer.put(key);
+        }
+        buffer.put(Byte::A as u8);
+        assert_eq!("안가나녕", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("안간녕", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_some());
+        assert_eq!("안녕", buffer.to_string());
+        assert_eq!(1, buffer.cursor());
+        assert!(buffer.pop().is_some());
+        assert_eq!("녕", buffer.to_string());
+    }
+
+    #[test]
+    fn test_events() {
+        let mut buffer = Buffer::default();
+        let expected = vec![
+            vec![Event::Preedit('ㅇ')],
+            vec![Event::Preedit('으')],
+            vec![Event::Preedit('은')],
+            vec![Event::Preedit('읁')],
+            vec![Event::Commit("은".to_string()), Event::Preedit('즈')],
+            vec![Event::Rejected(b'1')],
+            vec![Event::Commit("즈".to_string()), Event::Preedit('ㅏ')],
+        ];
+        for (key, events) in "dmswm1k".bytes().zip(expected) {
+            assert_eq!(events, buffer.put_event(key));
+        }
+
+        assert_eq!(
+            vec![Event::Deleted, Event::Deleted, Event::Preedit('즈')],
+            buffer.pop_event()
+        );
+        assert_eq!(vec![Event::Preedit('ㅈ')], buffer.pop_event());
+        assert_eq!(
+            vec![Event::Deleted, Event::Deleted, Event::Preedit('은')],
+            buffer.pop_event()
+        );
+        assert_eq!("은", buffer.to_string());
+        assert_eq!(
+            vec![Event::Commit("은".to_string()), Event::Preedit('ㄱ')],
+            buffer.put_event(b'r')
+        );
+        assert_eq!(vec![Event::Preedit('가')], buffer.put_event(b'k'));
+        assert_eq!("은가", buffer.to_string());
+    }
+
+    #[test]
+    fn test_events_by_keystroke() {
+        let mut buffer = Buffer::default();
+        buffer.set_pop_mode(PopMode::Keystroke);
+        for key in "dmswm".bytes() {
+            buffer.put_event(key);
+        }
+        assert_eq!(
+            vec![Event::Deleted, Event::Deleted, Event::Preedit('읁')],
+            buffer.pop_event()
+        );
+        assert_eq!(vec![Event::Preedit('은')], buffer.pop_event());
+        assert_eq!("은", buffer.to_string());
+    }
+
+    #[test]
+    fn test_events_after_moving_cursor() {
+        let mut buffer = Buffer::default();
+        for key in "dkssud".bytes() {
+            buffer.put_event(key);
+        }
+        buffer.set_cursor(1);
+        assert_eq!(vec![Event::Preedit('ㄱ')], buffer.put_event(b'r'));
+        assert_eq!(vec![Event::Preedit('가')], buffer.put_event(b'k'));
+        assert_eq!(
+            vec![Event::Commit("가".to_string()), Event::Preedit('ㅏ')],
+            buffer.put_event(b'k')
+        );
+        assert_eq!("안가ㅏ녕", buffer.to_string());
+        assert_eq!(
+            vec![Event::Deleted, Event::Deleted, Event::Preedit('가')],
+            buffer.pop_event()
+        );
+        assert_eq!(vec![Event::Preedit('ㄱ')], buffer.pop_event());
+        assert_eq!(
+            vec![Event::Deleted, Event::Deleted, Event::Preedit('안')],
+            buffer.pop_event()
+        );
+        assert_eq!("안녕", buffer.to_string());
+    }
+
+    #[test]
+    fn test_streaming() {
+        let mut buffer = Buffer::default();
+        buffer.set_streaming(true);
+        for key in "dmswm".chars() {
+            buffer.put(key);
+        }
+        assert_eq!(1, buffer.syllables.len());
+        assert_eq!("은", buffer.drain().collect::<String>());
+        assert_eq!("", buffer.drain().collect::<String>());
+        assert!(buffer.undo().is_some());
+        assert_eq!("", buffer.to_string());
+        assert!(buffer.undo().is_none());
+        assert!(buffer.redo().is_some());
+        assert_eq!("즈", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_none());
+
+        for key in "dkssud".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("안", buffer.drain().collect::<String>());
+        buffer.set_cursor(0);
+        buffer.put(Byte::G as u8);
+        buffer.put(Byte::A as u8);
+        buffer.put(Byte::N as u8);
+        assert_eq!("", buffer.drain().collect::<String>());
+        assert_eq!("간녕", buffer.to_string());
+        buffer.put(Byte::A as u8);
+        assert_eq!("가", buffer.drain().collect::<String>());
+        assert_eq!("나녕", buffer.to_string());
+        assert_eq!(1, buffer.cursor());
+        buffer.put(Byte::G as u8);
+        assert_eq!("낙녕", buffer.out());
+    }
+
+    #[test]
+    fn test_streaming_keeps_history() {
+        let mut buffer = Buffer::default();
+        buffer.set_streaming(true);
+        for key in "rkrk".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("가", buffer.to_string());
+        assert!(buffer.undo().is_some());
+        assert_eq!("", buffer.to_string());
+        assert!(buffer.redo().is_some());
+        for key in "rkdl".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("이", buffer.to_string());
+        assert!(buffer.undo().is_some());
+        assert_eq!("", buffer.to_string());
+        assert!(buffer.undo().is_none());
+        assert_eq!("가가가", buffer.drain().collect::<String>());
+
+        buffer.set_pop_mode(PopMode::Keystroke);
+        for key in "dmswm".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("즈", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("", buffer.to_string());
+        assert!(buffer.pop().is_none());
+
+        buffer.set_pop_mode(PopMode::Jamo);
+        for key in "dmswm".chars() {
+            buffer.put(key);
+        }
+        assert!(buffer.pop().is_some());
+        assert_eq!("ㅈ", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_none());
+        assert_eq!("은은", buffer.drain().collect::<String>());
+    }
+
+    #[test]
+    fn test_streaming_events() {
+        let mut buffer = Buffer::default();
+        buffer.set_streaming(true);
+        buffer.set_output(Output::Conjoining);
+        for key in "rksk".bytes() {
+            buffer.put_event(key);
+        }
+        assert_eq!(
+            vec![
+                Event::Commit("\u{1102}\u{1161}".to_string()),
+                Event::Preedit('ㅏ')
+            ],
+            buffer.put_event(b'k')
+        );
+        assert_eq!(
+            "\u{1100}\u{1161}\u{1102}\u{1161}",
+            buffer.drain().collect::<String>()
+        );
+        assert_eq!("\u{115f}\u{1161}", buffer.out());
+    }
+
+    #[test]
+    fn test_pass_through() {
+        let mut buffer = Buffer::default();
+        assert_eq!(Some(' '), buffer.put(' '));
+        buffer.set_pass_through(true);
+        for key in "rkA 1".chars() {
+            assert!(buffer.put(key).is_none());
+        }
+        buffer.put(Byte::K as u8);
+        buffer.put(Byte::A as u8);
+        assert_eq!("가A 1카", buffer.to_string());
+
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_some());
+        assert_eq!("가A ", buffer.to_string());
+        buffer.put(Byte::A as u8);
+        assert_eq!("가A ㅏ", buffer.to_string());
+        buffer.set_output(Output::Conjoining);
+        assert_eq!("\u{1100}\u{1161}A \u{115f}\u{1161}", buffer.out());
+    }
+
+    #[test]
+    fn test_pass_through_events() {
+        let mut buffer = Buffer::default();
+        buffer.set_pass_through(true);
+        buffer.set_streaming(true);
+        buffer.put_event(b'r');
+        buffer.put_event(b'k');
+        assert_eq!(
+            vec![Event::Commit("가.".to_string())],
+            buffer.put_event(b'.')
+        );
+        assert_eq!(vec![Event::Commit(" ".to_string())], buffer.put_event(b' '));
+        assert_eq!(vec![Event::Preedit('ㄴ')], buffer.put_event(b's'));
+        assert_eq!("가. ", buffer.drain().collect::<String>());
+        assert_eq!("ㄴ", buffer.to_string());
+    }
+
+    #[test]
+    fn test_shift_fallback() {
+        let mut buffer = Buffer::default();
+        buffer.set_shift_fallback(true);
+        for key in "DKSSUD".chars() {
+            assert!(buffer.put(key).is_none());
+        }
+        assert_eq!("안녕", buffer.out());
+        for key in "QwERT".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("ㅃㅈㄸㄲㅆ", buffer.out());
+
+        buffer.set_pass_through(true);
+        buffer.put('G');
+        buffer.put('1');
+        buffer.put('!');
+        assert_eq!("ㅎ1!", buffer.out());
+
+        buffer.set_shift_fallback(false);
+        buffer.put('G');
+        assert_eq!("G", buffer.out());
+    }
+
+    #[test]
+    fn test_compound_jamo() {
+        let mut buffer = Buffer::default();
+        for key in "rtkhk".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("ㄱ사ㅗㅏ", buffer.out());
+
+        buffer.set_compound_jamo(true);
+        for key in "mlqtfgfr".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("ㅢㅄㅀㄺ", buffer.to_string());
+        buffer.put(Byte::A as u8);
+        assert_eq!("ㅢㅄㅀㄹ가", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_some());
+        assert_eq!("ㅢㅄㅀㄹ", buffer.to_string());
+        buffer.put(Byte::M as u8);
+        assert_eq!("ㅢㅄㅀㄻ", buffer.out());
+
+        buffer.set_pop_mode(PopMode::Keystroke);
+        for key in "rtk".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("ㄱ사", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("ㄳ", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        buffer.put(Byte::A as u8);
+        assert_eq!("가", buffer.to_string());
+    }
+
+    #[test]
+    fn test_double_tap() {
+        let mut buffer = Buffer::default();
+        for key in "ttkd".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("ㅅ상", buffer.out());
+
+        buffer.set_double_tap(true);
+        for key in "ttkdrr".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("쌍ㄲ", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("쌍ㄱ", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("쌍", buffer.out());
+
+        for key in "rkrr".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("갂", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("각", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("가", buffer.out());
+
+        buffer.put('R');
+        assert!(buffer.pop().is_some());
+        assert_eq!("", buffer.to_string());
+
+        for key in "dlTt".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("있ㅅ", buffer.to_string());
+        buffer.put(Byte::EO as u8);
+        assert_eq!("있서", buffer.out());
+
+        buffer.set_compound_jamo(true);
+        for key in "rrrt".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("ㄲㄳ", buffer.out());
+    }
+
+    #[test]
+    fn test_moachigi() {
+        let mut buffer = Buffer::default();
+        for key in "kr".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("ㅏㄱ", buffer.out());
+
+        buffer.set_moachigi(true);
+        for key in "kdkrhkr".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("아가ㅗ가", buffer.out());
+
+        for key in "krs".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("간", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("가", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("ㅏ", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("", buffer.to_string());
+
+        buffer.set_compound_jamo(true);
+        buffer.set_pop_mode(PopMode::Keystroke);
+        for key in "hkrsk".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("과나", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("관", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert!(buffer.pop().is_some());
+        assert_eq!("ㅘ", buffer.to_string());
+
+        buffer.move_left();
+        buffer.move_right();
+        buffer.put(Byte::G as u8);
+        assert_eq!("ㅘㄱ", buffer.to_string());
+    }
+
+    #[test]
+    fn test_pop_after_undo() {
+        let mut buffer = Buffer::default();
+        buffer.put('r');
+        buffer.put('k');
+        assert!(buffer.undo().is_some());
+        assert_eq!("ㄱ", buffer.to_string());
+        buffer.put('k');
+        assert_eq!("가", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("ㄱ", buffer.to_string());
+    }
+
+    #[test]
+    fn test_pop_after_changing_rules() {
+        let mut buffer = Buffer::default();
+        buffer.put('r');
+        buffer.put('k');
+        buffer.set_double_tap(true);
+        buffer.put('s');
+        assert_eq!("간", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("가", buffer.to_string());
+        assert!(buffer.pop().is_some());
+        assert_eq!("ㄱ", buffer.to_string());
+
+        buffer.set_moachigi(true);
+        buffer.put('k');
+        buffer.put('r');
+        assert!(buffer.pop().is_some());
+        assert_eq!("가", buffer.to_string());
+    }
+
+    #[test]
+    fn test_conjoining_output() {
+        let mut buffer = Buffer::default();
+        buffer.set_output(Output::Conjoining);
+        buffer.put(Byte::NG as u8);
+        buffer.put(Byte::EU as u8);
+        buffer.put(Byte::N as u8);
+        buffer.put(Byte::J as u8);
+        buffer.put(Byte::H as u8);
+        buffer.put(Byte::O as u8);
+        buffer.put(Byte::A as u8);
+        buffer.put(Byte::EU as u8);
+
+        assert_eq!(
+            "\u{110b}\u{1173}\u{11ac}\u{1112}\u{116a}\u{115f}\u{1173}",
+            buffer.out()
+        );
+        assert_eq!("", buffer.to_string());
+    }
+
+    #[test]
+    fn test_conjoining_output_round_trip() {
+        let mut buffer = Buffer::default();
+        buffer.set_output(Output::Conjoining);
+        for key in "rrkk".chars() {
+            buffer.put(key);
+        }
+        let conjoining = buffer.out();
+        assert_eq!(
+            "\u{1100}\u{1160}\u{1100}\u{1161}\u{115f}\u{1161}",
+            conjoining
+        );
+        assert_eq!("ㄱ가ㅏ", crate::from_conjoining(&conjoining));
+    }
 }
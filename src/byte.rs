@@ -1,3 +1,4 @@
+use crate::syllable::{FinalConsonant, InitialConsonant, MedialVowel};
 use std::convert::TryFrom;
 
 /// This is the intermediate representation of the Buffer input.
@@ -82,7 +83,7 @@ impl TryFrom<u8> for Byte {
             0x45 | 0x4f | 0x50 | 0x51 | 0x52 | 0x54 | 0x57 | 0x61 | 0x62 | 0x63 | 0x64 | 0x65
             | 0x66 | 0x67 | 0x68 | 0x69 | 0x6a | 0x6b | 0x6c | 0x6d | 0x6e | 0x6f | 0x70 | 0x71
             | 0x72 | 0x73 | 0x74 | 0x75 | 0x76 | 0x77 | 0x78 | 0x79 | 0x7a => unsafe {
-                Ok(std::mem::transmute(byte))
+                Ok(std::mem::transmute::<u8, Byte>(byte))
             },
             _ => Err(byte),
         }
@@ -104,30 +105,94 @@ impl TryFrom<char> for Byte {
     }
 }
 
+/// The archaic consonants and clusters of Old Hangul like ㅿ have no key and
+/// are returned as error.
+impl TryFrom<InitialConsonant> for Byte {
+    type Error = InitialConsonant;
+    fn try_from(ic: InitialConsonant) -> Result<Self, Self::Error> {
+        match ic {
+            InitialConsonant::G => Ok(Self::G),
+            InitialConsonant::KK => Ok(Self::KK),
+            InitialConsonant::N => Ok(Self::N),
+            InitialConsonant::D => Ok(Self::D),
+            InitialConsonant::TT => Ok(Self::TT),
+            InitialConsonant::R => Ok(Self::R),
+            InitialConsonant::M => Ok(Self::M),
+            InitialConsonant::B => Ok(Self::B),
+            InitialConsonant::PP => Ok(Self::PP),
+            InitialConsonant::S => Ok(Self::S),
+            InitialConsonant::SS => Ok(Self::SS),
+            InitialConsonant::NG => Ok(Self::NG),
+            InitialConsonant::J => Ok(Self::J),
+            InitialConsonant::JJ => Ok(Self::JJ),
+            InitialConsonant::CH => Ok(Self::CH),
+            InitialConsonant::K => Ok(Self::K),
+            InitialConsonant::T => Ok(Self::T),
+            InitialConsonant::P => Ok(Self::P),
+            InitialConsonant::H => Ok(Self::H),
+            _ => Err(ic),
+        }
+    }
+}
+
+/// Compound vowels like ㅘ have no key of their own and are returned as error.
+impl TryFrom<MedialVowel> for Byte {
+    type Error = MedialVowel;
+    fn try_from(mv: MedialVowel) -> Result<Self, Self::Error> {
+        match mv {
+            MedialVowel::A => Ok(Self::A),
+            MedialVowel::AE => Ok(Self::AE),
+            MedialVowel::YA => Ok(Self::YA),
+            MedialVowel::YAE => Ok(Self::YAE),
+            MedialVowel::EO => Ok(Self::EO),
+            MedialVowel::E => Ok(Self::E),
+            MedialVowel::YEO => Ok(Self::YEO),
+            MedialVowel::YE => Ok(Self::YE),
+            MedialVowel::O => Ok(Self::O),
+            MedialVowel::YO => Ok(Self::YO),
+            MedialVowel::U => Ok(Self::U),
+            MedialVowel::YU => Ok(Self::YU),
+            MedialVowel::EU => Ok(Self::EU),
+            MedialVowel::I => Ok(Self::I),
+            _ => Err(mv),
+        }
+    }
+}
+
+/// Compound final consonants like ㄳ have no key of their own and are
+/// returned as error.
+impl TryFrom<FinalConsonant> for Byte {
+    type Error = FinalConsonant;
+    fn try_from(fc: FinalConsonant) -> Result<Self, Self::Error> {
+        let ic = InitialConsonant::try_from(fc)?;
+        Self::try_from(ic).map_err(|_| fc)
+    }
+}
+
 impl Byte {
     pub fn is_consonant(&self) -> bool {
-        match self {
+        matches!(
+            self,
             Self::TT
-            | Self::PP
-            | Self::KK
-            | Self::SS
-            | Self::JJ
-            | Self::M
-            | Self::CH
-            | Self::NG
-            | Self::D
-            | Self::R
-            | Self::H
-            | Self::B
-            | Self::G
-            | Self::N
-            | Self::S
-            | Self::P
-            | Self::J
-            | Self::T
-            | Self::K => true,
-            _ => false,
-        }
+                | Self::PP
+                | Self::KK
+                | Self::SS
+                | Self::JJ
+                | Self::M
+                | Self::CH
+                | Self::NG
+                | Self::D
+                | Self::R
+                | Self::H
+                | Self::B
+                | Self::G
+                | Self::N
+                | Self::S
+                | Self::P
+                | Self::J
+                | Self::T
+                | Self::K
+        )
     }
     pub fn is_vowel(&self) -> bool {
         !self.is_consonant()
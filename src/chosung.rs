@@ -0,0 +1,119 @@
+use crate::syllable::{decompose, InitialConsonant};
+use std::convert::TryFrom;
+
+/// Takes the initial consonant(초성) of each Hangul syllable, as Hangul
+/// Compatibility Jamo. Other characters are kept as they are.
+///
+/// # Example
+/// ```
+/// use sejong::chosung;
+/// assert_eq!(chosung("안녕하세요"), "ㅇㄴㅎㅅㅇ");
+/// assert_eq!(chosung("한글 2.0"), "ㅎㄱ 2.0");
+/// ```
+pub fn chosung(hangul: &str) -> String {
+    hangul
+        .chars()
+        .map(|c| decompose(c).map_or(c, |(ic, _, _)| char::from(ic)))
+        .collect()
+}
+
+/// Finds where the query first matches in the candidate, in chars. An initial
+/// consonant in the query matches any syllable starting with it, and other
+/// characters match only themselves. So the query can be chosung like
+/// "ㅇㄴ", or what a [`Buffer`](crate::Buffer) holds in the middle of typing
+/// like "안ㄴ". Returns `None` when the query doesn't match.
+///
+/// # Example
+/// ```
+/// use sejong::find_chosung;
+/// assert_eq!(find_chosung("ㅇㄴ", "안녕"), Some(0));
+/// assert_eq!(find_chosung("안ㄴ", "안녕"), Some(0));
+/// assert_eq!(find_chosung("ㅎㅅ", "안녕하세요"), Some(2));
+/// assert_eq!(find_chosung("아ㄴ", "안녕"), None);
+/// ```
+pub fn find_chosung(query: &str, candidate: &str) -> Option<usize> {
+    let query: Vec<char> = query.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    let last_start = candidate.len().checked_sub(query.len())?;
+    (0..=last_start).find(|&start| {
+        query
+            .iter()
+            .zip(&candidate[start..])
+            .all(|(q, c)| matches(*q, *c))
+    })
+}
+
+/// Finds the candidates that the query matches with [`find_chosung`]. Those
+/// matched earlier come first, and those matched at the same position are in
+/// the order given.
+///
+/// # Example
+/// ```
+/// use sejong::{search_chosung, Buffer};
+/// let mut buf = Buffer::default();
+/// for key in "dkss".chars() {
+///     buf.put(key);
+/// }
+/// assert_eq!(buf.to_string(), "안ㄴ");
+/// let candidates = ["잘 안녕", "안녕", "아니요", "안내"];
+/// assert_eq!(search_chosung(&buf.to_string(), candidates), ["안녕", "안내", "잘 안녕"]);
+/// ```
+pub fn search_chosung<'a, I>(query: &str, candidates: I) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut found: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter_map(|candidate| Some((find_chosung(query, candidate)?, candidate)))
+        .collect();
+    found.sort_by_key(|(position, _)| *position);
+    found.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+fn matches(query: char, c: char) -> bool {
+    if query == c {
+        return true;
+    }
+    match (InitialConsonant::try_from(query), decompose(c)) {
+        (Ok(initial), Some((ic, _, _))) => initial == ic,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chosung() {
+        assert_eq!("ㄲㄸㅃ", chosung("꽃떡빵"));
+        assert_eq!("ㄱㅏ", chosung("ㄱㅏ"));
+        assert_eq!("", chosung(""));
+    }
+
+    #[test]
+    fn test_find_chosung() {
+        assert_eq!(Some(0), find_chosung("", "안녕"));
+        assert_eq!(Some(1), find_chosung("ㄴ", "안녕"));
+        assert_eq!(Some(0), find_chosung("ㅇ녕", "안녕"));
+        assert_eq!(Some(2), find_chosung("ㅎㄱ", "옛 한글"));
+        assert_eq!(Some(0), find_chosung("ㄱ", "ㄱ"));
+        assert_eq!(None, find_chosung("ㄱ", "ㄲ"));
+        assert_eq!(None, find_chosung("ㅇㄴㅎ", "안녕"));
+        assert_eq!(None, find_chosung("ㅏ", "안녕"));
+    }
+
+    #[test]
+    fn test_search_chosung() {
+        let candidates = vec!["시작하기", "하기", "기차", "가방"];
+        assert_eq!(
+            search_chosung("ㄱ", candidates.clone()),
+            ["기차", "가방", "하기", "시작하기"]
+        );
+        assert_eq!(
+            search_chosung("ㅎㄱ", candidates.clone()),
+            ["하기", "시작하기"]
+        );
+        assert!(search_chosung("ㅋ", candidates).is_empty());
+    }
+}
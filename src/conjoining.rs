@@ -0,0 +1,129 @@
+use crate::syllable::{
+    compose, decompose, FinalConsonant, InitialConsonant, MedialVowel, Syllable,
+};
+use std::convert::TryFrom;
+
+pub(crate) const CHOSEONG_FILLER: char = '\u{115f}';
+pub(crate) const JUNGSEONG_FILLER: char = '\u{1160}';
+
+/// Converts Hangul syllables and Hangul Compatibility Jamo into Hangul
+/// Jamo(conjoining), U+1100 to U+11FF. Syllables are decomposed the way NFD
+/// does it. Compatibility Jamo are written with the fillers U+115F and U+1160
+/// in place of the initial consonant or the vowel they miss, so they don't
+/// join the Jamo after them. Other characters are kept as they are.
+///
+/// # Example
+/// ```
+/// use sejong::to_conjoining;
+/// assert_eq!(to_conjoining("한ㄱ"), "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1160}");
+/// assert_eq!(to_conjoining("ㄳ!"), "\u{115f}\u{1160}\u{11aa}!");
+/// ```
+pub fn to_conjoining(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() * 3);
+    for c in s.chars() {
+        match Syllable::try_from(c) {
+            Ok(syl) => result.extend(syl.conjoining()),
+            Err(c) => result.push(c),
+        }
+    }
+    result
+}
+
+/// Converts Hangul Jamo(conjoining) back into Hangul syllables, the way NFC
+/// composes them. Jamo that don't make a syllable become Hangul Compatibility
+/// Jamo, and the fillers U+115F and U+1160 are dropped. Other characters are
+/// kept as they are.
+///
+/// # Example
+/// ```
+/// use sejong::from_conjoining;
+/// assert_eq!(from_conjoining("\u{1112}\u{1161}\u{11ab}\u{1100}"), "한ㄱ");
+/// assert_eq!(from_conjoining("\u{115f}\u{1161}"), "ㅏ");
+/// ```
+pub fn from_conjoining(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if let Some(ic) = InitialConsonant::from_conjoining(c) {
+            match chars.peek().copied().and_then(MedialVowel::from_conjoining) {
+                Some(mv) => {
+                    chars.next();
+                    let fc = chars
+                        .peek()
+                        .copied()
+                        .and_then(FinalConsonant::from_conjoining);
+                    if fc.is_some() {
+                        chars.next();
+                    }
+                    result.extend(compose(ic, mv, fc));
+                }
+                None => result.push(ic.into()),
+            }
+        } else if let Some(mv) = MedialVowel::from_conjoining(c) {
+            result.push(mv.into());
+        } else if let Some(fc) = FinalConsonant::from_conjoining(c) {
+            result.push(fc.into());
+        } else if let Some((ic, mv, None)) = decompose(c) {
+            match chars
+                .peek()
+                .copied()
+                .and_then(FinalConsonant::from_conjoining)
+            {
+                Some(fc) => {
+                    chars.next();
+                    result.extend(compose(ic, mv, Some(fc)));
+                }
+                None => result.push(c),
+            }
+        } else if c != CHOSEONG_FILLER && c != JUNGSEONG_FILLER {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_every_syllable() {
+        let text: String = (0xac00..=0xd7a3)
+            .map(|code| std::char::from_u32(code).unwrap())
+            .collect();
+        let conjoining = to_conjoining(&text);
+        assert!(conjoining
+            .chars()
+            .all(|c| ('\u{1100}'..='\u{11ff}').contains(&c)));
+        assert_eq!(from_conjoining(&conjoining), text);
+    }
+
+    #[test]
+    fn test_round_trip_compatibility_jamo() {
+        for code in 0x3131..=0x3163 {
+            let text = std::char::from_u32(code).unwrap().to_string();
+            assert_eq!(from_conjoining(&to_conjoining(&text)), text);
+        }
+    }
+
+    #[test]
+    fn test_from_conjoining_fillers_and_partial_syllables() {
+        assert_eq!(from_conjoining("\u{1100}\u{1160}"), "ㄱ");
+        assert_eq!(from_conjoining("\u{115f}\u{1160}\u{11a8}"), "ㄱ");
+        assert_eq!(from_conjoining("가\u{11a8}"), "각");
+        assert_eq!(from_conjoining("각\u{11a8}"), "각ㄱ");
+        assert_eq!(from_conjoining("\u{1100}\u{1100}\u{1161}"), "ㄱ가");
+    }
+
+    #[test]
+    fn test_round_trip_partial_syllables() {
+        let text = "ㄱ가ㅏ아ㄳ가ㄱ";
+        let conjoining = to_conjoining(text);
+        assert_eq!(
+            "\u{1100}\u{1160}\u{1100}\u{1161}\u{115f}\u{1161}\u{110b}\u{1161}\
+             \u{115f}\u{1160}\u{11aa}\u{1100}\u{1161}\u{1100}\u{1160}",
+            conjoining
+        );
+        assert_eq!(from_conjoining(&conjoining), text);
+    }
+}
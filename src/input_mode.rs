@@ -0,0 +1,170 @@
+use crate::buffer::Buffer;
+use crate::byte::Byte;
+use crate::reverse::{keystrokes, to_qwerty};
+use crate::syllable::decompose;
+use std::convert::TryFrom;
+
+const HANGUL_THRESHOLD: f64 = 0.8;
+const QWERTY_THRESHOLD: f64 = 0.5;
+
+/// How likely it is, from 0.0 to 1.0, that the letters were meant to be Hangul
+/// but typed while the keyboard was in English mode, like "gksrmf" for "한글".
+///
+/// It is the share of the letters that are keys of the 2-set layout, as read
+/// by [`Byte`], times the share of those keys that make whole syllables when
+/// they are put into a [`Buffer`]. English words make Jamo that hardly
+/// compose: "hello" is "ㅗ디ㅣㅐ". Characters other than ASCII letters are not
+/// counted.
+///
+/// # Example
+/// ```
+/// use sejong::hangul_likelihood;
+/// assert_eq!(hangul_likelihood("gksrmf"), 1.0);
+/// assert_eq!(hangul_likelihood("test"), 0.0);
+/// assert!(hangul_likelihood("hello") < 0.5);
+/// ```
+pub fn hangul_likelihood(ascii: &str) -> f64 {
+    let letters = ascii.chars().filter(char::is_ascii_alphabetic).count();
+    if letters == 0 {
+        return 0.0;
+    }
+    let valid = ascii
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic() && Byte::try_from(*c).is_ok())
+        .count();
+    let (mut composed, mut lone) = (0, 0);
+    for c in type_keys(ascii).chars() {
+        match (decompose(c), keystrokes(c)) {
+            (Some(_), Some(keys)) => composed += keys.len(),
+            (None, Some(keys)) => lone += keys.len(),
+            _ => {}
+        }
+    }
+    if composed == 0 {
+        return 0.0;
+    }
+    valid as f64 / letters as f64 * composed as f64 / (composed + lone) as f64
+}
+
+/// Converts the letters into Hangul with the 2-set layout when they are likely
+/// to be Hangul typed in English mode, with [`hangul_likelihood`] of 0.8 or
+/// more. Returns `None` otherwise. Characters that aren't keys of the layout
+/// are kept as they are.
+///
+/// # Example
+/// ```
+/// use sejong::suggest_hangul;
+/// assert_eq!(suggest_hangul("dkssud, gksrmf!"), Some("안녕, 한글!".to_string()));
+/// assert_eq!(suggest_hangul("hello"), None);
+/// ```
+pub fn suggest_hangul(ascii: &str) -> Option<String> {
+    match hangul_likelihood(ascii) >= HANGUL_THRESHOLD {
+        true => Some(type_keys(ascii)),
+        false => None,
+    }
+}
+
+/// How likely it is, from 0.0 to 1.0, that the Hangul was meant to be English
+/// but typed while the keyboard was in Korean mode, like "ㅗ디ㅣㅐ" for
+/// "hello".
+///
+/// It is the share of the keys typing the Hangul that are left as Jamo outside
+/// any syllable. A Jamo repeated like "ㅋㅋ" or "ㅠㅠ" is taken as written on
+/// purpose and not counted.
+///
+/// # Example
+/// ```
+/// use sejong::qwerty_likelihood;
+/// assert_eq!(qwerty_likelihood("ㅅㄷㄴㅅ"), 1.0);
+/// assert_eq!(qwerty_likelihood("안녕하세요"), 0.0);
+/// assert_eq!(qwerty_likelihood("ㅋㅋㅋ"), 0.0);
+/// ```
+pub fn qwerty_likelihood(hangul: &str) -> f64 {
+    let chars: Vec<char> = hangul.chars().collect();
+    let (mut composed, mut lone) = (0, 0);
+    for (i, c) in chars.iter().enumerate() {
+        let keys = match keystrokes(*c) {
+            Some(keys) => keys.len(),
+            None => continue,
+        };
+        let repeated = (i > 0 && chars[i - 1] == *c) || chars.get(i + 1) == Some(c);
+        match decompose(*c) {
+            Some(_) => composed += keys,
+            None if repeated => {}
+            None => lone += keys,
+        }
+    }
+    if lone == 0 {
+        return 0.0;
+    }
+    lone as f64 / (composed + lone) as f64
+}
+
+/// Converts the Hangul back into the QWERTY keys that type it when it is likely
+/// to be English typed in Korean mode, with [`qwerty_likelihood`] of 0.5 or
+/// more. Returns `None` otherwise. See [`to_qwerty`](crate::to_qwerty).
+///
+/// # Example
+/// ```
+/// use sejong::suggest_qwerty;
+/// assert_eq!(suggest_qwerty("ㅗ디ㅣㅐ"), Some("hello".to_string()));
+/// assert_eq!(suggest_qwerty("안녕ㅋㅋ"), None);
+/// ```
+pub fn suggest_qwerty(hangul: &str) -> Option<String> {
+    match qwerty_likelihood(hangul) >= QWERTY_THRESHOLD {
+        true => Some(to_qwerty(hangul)),
+        false => None,
+    }
+}
+
+fn type_keys(ascii: &str) -> String {
+    let mut buffer = Buffer::default();
+    buffer.set_pass_through(true);
+    for c in ascii.chars() {
+        buffer.put(c);
+    }
+    buffer.out()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hangul_likelihood() {
+        assert_eq!(0.0, hangul_likelihood(""));
+        assert_eq!(0.0, hangul_likelihood("123 !"));
+        assert_eq!(1.0, hangul_likelihood("dkssud 123"));
+        assert_eq!(0.4, hangul_likelihood("hello"));
+        assert_eq!(0.0, hangul_likelihood("korea"));
+        assert!(hangul_likelihood("Dkssud") < HANGUL_THRESHOLD);
+    }
+
+    #[test]
+    fn test_suggest_hangul() {
+        assert_eq!(
+            Some("한글 사랑해!".to_string()),
+            suggest_hangul("gksrmf tkfkdgo!")
+        );
+        assert_eq!(Some("닭".to_string()), suggest_hangul("ekfr"));
+        assert_eq!(None, suggest_hangul("hello world"));
+        assert_eq!(None, suggest_hangul("string"));
+        assert_eq!(None, suggest_hangul(""));
+    }
+
+    #[test]
+    fn test_qwerty_likelihood() {
+        assert_eq!(0.0, qwerty_likelihood(""));
+        assert_eq!(0.0, qwerty_likelihood("hello"));
+        assert_eq!(0.6, qwerty_likelihood("ㅗ디ㅣㅐ"));
+        assert_eq!(0.0, qwerty_likelihood("ㅠㅠ 슬퍼"));
+        assert!(qwerty_likelihood("안녕ㅎ") < QWERTY_THRESHOLD);
+    }
+
+    #[test]
+    fn test_suggest_qwerty() {
+        assert_eq!(Some("test 123".to_string()), suggest_qwerty("ㅅㄷㄴㅅ 123"));
+        assert_eq!(Some("korea".to_string()), suggest_qwerty("ㅏㅐㄱㄷㅁ"));
+        assert_eq!(None, suggest_qwerty("안녕하세요"));
+    }
+}
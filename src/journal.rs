@@ -0,0 +1,75 @@
+use std::collections::VecDeque;
+
+/// Undo and redo stacks of changes. The oldest changes are dropped when there
+/// are more than `cap` of them to undo.
+#[derive(Clone)]
+pub(crate) struct Journal<T> {
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    cap: usize,
+}
+
+impl<T> Journal<T> {
+    pub fn with_cap(cap: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            cap,
+        }
+    }
+
+    pub fn cap(&self) -> usize {
+        self.cap
+    }
+
+    pub fn set_cap(&mut self, cap: usize) {
+        self.cap = cap;
+        while self.undo.len() > cap {
+            self.undo.pop_front();
+        }
+        // The change to redo next is the last one, so the farthest are dropped.
+        if self.redo.len() > cap {
+            self.redo.drain(..self.redo.len() - cap);
+        }
+    }
+
+    /// Records a new change. Nothing can be redone after it.
+    pub fn record(&mut self, change: T) {
+        self.redo.clear();
+        self.push_undo(change);
+    }
+
+    /// Goes through the changes to undo from the last one, keeping them while
+    /// `keep` returns true. The first one it rejects and all before it are
+    /// dropped.
+    pub fn retain_recent(&mut self, mut keep: impl FnMut(&mut T) -> bool) {
+        if let Some(rejected) = self.undo.iter_mut().rev().position(|change| !keep(change)) {
+            self.undo.drain(..self.undo.len() - rejected);
+        }
+    }
+
+    /// Moves the last change to the redo stack and returns it to be reverted.
+    pub fn undo(&mut self) -> Option<&T> {
+        let change = self.undo.pop_back()?;
+        self.redo.push(change);
+        self.redo.last()
+    }
+
+    /// Moves the last reverted change back to the undo stack and returns it to
+    /// be made again.
+    pub fn redo(&mut self) -> Option<&T> {
+        let change = self.redo.pop()?;
+        self.push_undo(change);
+        self.undo.back()
+    }
+
+    fn push_undo(&mut self, change: T) {
+        if self.cap == 0 {
+            return;
+        }
+        if self.undo.len() == self.cap {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(change);
+    }
+}
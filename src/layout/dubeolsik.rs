@@ -0,0 +1,24 @@
+use super::Layout;
+use crate::byte::Byte;
+use crate::syllable::Jamo;
+use std::convert::TryFrom;
+
+/// The standard Korean 2-set(Dubeolsik) layout on a QWERTY keyboard.
+/// This is the default layout of [`Buffer`](crate::Buffer).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dubeolsik;
+
+impl Layout for Dubeolsik {
+    fn map(&self, key: char) -> Option<Jamo> {
+        Byte::try_from(key).ok().map(Jamo::from)
+    }
+
+    // Shift only makes the tense consonants and 'ㅒ' and 'ㅖ', so any other
+    // uppercase letter can stand for its lowercase one.
+    fn unshifted(&self, key: char) -> Option<char> {
+        match key.is_ascii_uppercase() {
+            true => Some(key.to_ascii_lowercase()),
+            false => None,
+        }
+    }
+}
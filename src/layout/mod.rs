@@ -0,0 +1,52 @@
+mod dubeolsik;
+mod old_hangul;
+mod sebeolsik;
+
+use crate::syllable::Jamo;
+
+pub use dubeolsik::Dubeolsik;
+pub use old_hangul::OldHangul;
+pub use sebeolsik::{Sebeolsik390, SebeolsikFinal};
+
+/// A keyboard layout decides which Hangul Jamo a key types.
+/// [`Buffer`](crate::Buffer) asks its layout to map every input before
+/// composing it, so a different layout can be used without touching the
+/// composition itself. How the typed Jamo are composed depends on the kind of
+/// [`Jamo`] the layout returns, see its variants.
+///
+/// # Example
+/// A 2-set layout for a keyboard that is set to Dvorak by the OS. The keys are
+/// translated back to their QWERTY positions before looking up the Jamo.
+/// ```
+/// use sejong::{Buffer, Dubeolsik, Jamo, Layout};
+///
+/// struct DvorakDubeolsik;
+///
+/// impl Layout for DvorakDubeolsik {
+///     fn map(&self, key: char) -> Option<Jamo> {
+///         const DVORAK: &str = "',.pyfgcrlaoeuidhtns;qjkxbmwvz\"<>PYFGCRLAOEUIDHTNS:QJKXBMWVZ";
+///         const QWERTY: &str = "qwertyuiopasdfghjkl;zxcvbnm,./QWERTYUIOPASDFGHJKL:ZXCVBNM<>?";
+///         let position = DVORAK.chars().position(|c| c == key)?;
+///         Dubeolsik.map(QWERTY.chars().nth(position)?)
+///     }
+/// }
+///
+/// let mut buf = Buffer::with_layout(DvorakDubeolsik);
+/// buf.put('e');
+/// buf.put('t');
+/// buf.put('o');
+/// assert_eq!(buf.to_string(), "안");
+/// ```
+pub trait Layout {
+    /// Returns the [`Jamo`] typed by `key`, or `None` when `key` doesn't
+    /// type any Jamo in this layout.
+    fn map(&self, key: char) -> Option<Jamo>;
+
+    /// Returns the key to read instead of `key` when `key` types nothing and
+    /// the shift fallback of [`Buffer`](crate::Buffer) is on. By default there
+    /// is none, as Shift types different Jamo in most layouts.
+    fn unshifted(&self, key: char) -> Option<char> {
+        let _ = key;
+        None
+    }
+}
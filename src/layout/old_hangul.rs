@@ -0,0 +1,145 @@
+use super::{Dubeolsik, Layout};
+use crate::syllable::{InitialConsonant, Jamo, MedialVowel};
+
+/// The 2-set layout for Old Hangul(옛한글), with the archaic Jamo that modern
+/// Hangul doesn't have.
+///
+/// The keys are those of [`Dubeolsik`], with four more: 'Z' types 'ㅿ', 'D'
+/// types 'ㆁ', 'G' types 'ㆆ' and 'K' types 'ㆍ'. 'ㆍ' and 'ㅣ' make 'ㆎ'. The
+/// initial consonant clusters, like 'ㅴ' from 'ㅂ', 'ㅅ' and 'ㄱ', are made when
+/// [`Buffer::set_initial_clusters`](crate::Buffer::set_initial_clusters) is on.
+/// Syllables with archaic Jamo have no precomposed character, so they are
+/// written in Hangul Jamo(conjoining), see [`Output`](crate::Output).
+///
+/// # Example
+/// ```
+/// use sejong::{Buffer, OldHangul, Output};
+/// let mut buf = Buffer::with_layout(OldHangul);
+/// buf.set_initial_clusters(true);
+/// buf.set_output(Output::Conjoining);
+/// for key in "qeKf".chars() {
+///     buf.put(key);
+/// }
+/// assert_eq!(buf.to_string(), "\u{1120}\u{119e}\u{11af}");
+/// buf.pop();
+/// buf.put('Z');
+/// assert_eq!(buf.out(), "\u{1120}\u{119e}\u{11eb}");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OldHangul;
+
+impl Layout for OldHangul {
+    fn map(&self, key: char) -> Option<Jamo> {
+        let jamo = match key {
+            'Z' => Jamo::Consonant(InitialConsonant::PANSIOS),
+            'D' => Jamo::Consonant(InitialConsonant::YESIEUNG),
+            'G' => Jamo::Consonant(InitialConsonant::YEORINHIEUH),
+            'K' => Jamo::Vowel(MedialVowel::ARAEA),
+            _ => return Dubeolsik.map(key),
+        };
+        Some(jamo)
+    }
+
+    fn unshifted(&self, key: char) -> Option<char> {
+        Dubeolsik.unshifted(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::type_keys;
+    use crate::{Buffer, Event, Output};
+
+    fn buffer() -> Buffer<OldHangul> {
+        let mut buffer = Buffer::with_layout(OldHangul);
+        buffer.set_initial_clusters(true);
+        buffer.set_output(Output::Conjoining);
+        buffer
+    }
+
+    #[test]
+    fn test_archaic_jamo() {
+        assert_eq!("\u{1140}\u{1161}", type_keys(buffer(), "Zk"));
+        assert_eq!("\u{110b}\u{1161}\u{11f0}", type_keys(buffer(), "dkD"));
+        assert_eq!(
+            "\u{110b}\u{1161}\u{114c}\u{1161}",
+            type_keys(buffer(), "dkDk")
+        );
+        assert_eq!("\u{1159}\u{1175}\u{11eb}", type_keys(buffer(), "GlZ"));
+        assert_eq!("\u{110b}\u{11a1}", type_keys(buffer(), "dKl"));
+        assert_eq!(
+            "\u{1140}\u{1160}\u{1140}\u{1160}",
+            type_keys(buffer(), "ZZ")
+        );
+        assert_eq!(
+            "\u{115f}\u{1161}\u{115f}\u{119e}",
+            type_keys(buffer(), "kK")
+        );
+    }
+
+    #[test]
+    fn test_initial_clusters() {
+        assert_eq!("\u{1122}\u{1161}", type_keys(buffer(), "qtrk"));
+        assert_eq!("\u{112b}\u{1173}", type_keys(buffer(), "qdm"));
+        assert_eq!(
+            "\u{1102}\u{1161}\u{1105}\u{1161}\u{11ba}\u{1106}\u{1161}\u{11af}\u{110a}\u{119e}\u{1106}\u{1175}",
+            type_keys(buffer(), "skfktakfTKal")
+        );
+        assert_eq!(
+            "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1160}",
+            type_keys(buffer(), "gksr")
+        );
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut buffer = buffer();
+        assert!(buffer.pop().is_none());
+        for key in "qtrKl".chars() {
+            buffer.put(key);
+        }
+        let expected = vec![
+            "\u{1122}\u{119e}",
+            "\u{1122}\u{1160}",
+            "\u{1121}\u{1160}",
+            "\u{1107}\u{1160}",
+            "",
+        ];
+        for state in expected {
+            assert!(buffer.pop().is_some());
+            assert_eq!(state, buffer.to_string());
+        }
+        assert!(buffer.pop().is_none());
+        assert_eq!(Some('1'), buffer.put('1'));
+        assert_eq!(Some('A'), buffer.put('A'));
+    }
+
+    #[test]
+    fn test_precomposed_output() {
+        let mut buffer = Buffer::with_layout(OldHangul);
+        for key in "dkssudZk".chars() {
+            buffer.put(key);
+        }
+        assert_eq!("안녕\u{1140}\u{1161}", buffer.to_string());
+        buffer.pop();
+        assert_eq!("안녕ㅿ", buffer.to_string());
+    }
+
+    #[test]
+    fn test_events() {
+        let mut buffer = Buffer::with_layout(OldHangul);
+        assert_eq!(vec![Event::Preedit('ㅿ')], buffer.put_event(b'Z'));
+        assert_eq!(
+            vec![Event::PreeditJamo("\u{1140}\u{1161}".to_string())],
+            buffer.put_event(b'k')
+        );
+        assert_eq!(
+            vec![
+                Event::Commit("\u{1140}\u{1161}".to_string()),
+                Event::Preedit('ㅏ')
+            ],
+            buffer.put_event(b'k')
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use super::Layout;
+use crate::syllable::{FinalConsonant, InitialConsonant, Jamo, MedialVowel};
+
+/// The 3-set(Sebeolsik) 390 layout on a QWERTY keyboard.
+///
+/// Initial consonants, vowels and final consonants have their own keys, so a
+/// final consonant never moves to the next syllable. Tense initial consonants
+/// are typed by pressing the plain consonant twice, compound vowels by their
+/// two halves, and ㄳ, ㄵ and ㄼ, which have no key, by their two halves.
+///
+/// # Example
+/// ```
+/// use sejong::{Buffer, Sebeolsik390};
+/// let mut buf = Buffer::with_layout(Sebeolsik390);
+/// for key in "jfsheamfncj4".chars() {
+///     buf.put(key);
+/// }
+/// assert_eq!(buf.to_string(), "안녕하세요");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sebeolsik390;
+
+/// The 3-set(Sebeolsik) Final layout on a QWERTY keyboard.
+///
+/// It shares the unshifted keys with [`Sebeolsik390`], but every final
+/// consonant, compound ones included, has its own key.
+///
+/// # Example
+/// ```
+/// use sejong::{Buffer, SebeolsikFinal};
+/// let mut buf = Buffer::with_layout(SebeolsikFinal);
+/// for key in "jfEkfV".chars() {
+///     buf.put(key);
+/// }
+/// assert_eq!(buf.to_string(), "앉갃");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SebeolsikFinal;
+
+impl Layout for Sebeolsik390 {
+    fn map(&self, key: char) -> Option<Jamo> {
+        let fc = match key {
+            '!' => FinalConsonant::J,
+            '#' => FinalConsonant::LP,
+            '$' => FinalConsonant::LT,
+            '%' => FinalConsonant::LH,
+            'A' => FinalConsonant::D,
+            'C' => FinalConsonant::LM,
+            'D' => FinalConsonant::LG,
+            'E' => FinalConsonant::K,
+            'F' => FinalConsonant::KK,
+            'Q' => FinalConsonant::P,
+            'S' => FinalConsonant::NH,
+            'V' => FinalConsonant::LS,
+            'W' => FinalConsonant::T,
+            'X' => FinalConsonant::BS,
+            'Z' => FinalConsonant::CH,
+            'R' => return Some(Jamo::Medial(MedialVowel::YAE)),
+            _ => return map_unshifted(key),
+        };
+        Some(Jamo::Final(fc))
+    }
+}
+
+impl Layout for SebeolsikFinal {
+    fn map(&self, key: char) -> Option<Jamo> {
+        let fc = match key {
+            '!' => FinalConsonant::KK,
+            '@' => FinalConsonant::LG,
+            '#' => FinalConsonant::J,
+            '$' => FinalConsonant::LP,
+            '%' => FinalConsonant::LT,
+            'A' => FinalConsonant::D,
+            'C' => FinalConsonant::K,
+            'D' => FinalConsonant::LB,
+            'E' => FinalConsonant::NJ,
+            'F' => FinalConsonant::LM,
+            'Q' => FinalConsonant::P,
+            'R' => FinalConsonant::LH,
+            'S' => FinalConsonant::NH,
+            'T' => FinalConsonant::LS,
+            'V' => FinalConsonant::GS,
+            'W' => FinalConsonant::T,
+            'X' => FinalConsonant::BS,
+            'Z' => FinalConsonant::CH,
+            'G' => return Some(Jamo::Medial(MedialVowel::YAE)),
+            _ => return map_unshifted(key),
+        };
+        Some(Jamo::Final(fc))
+    }
+}
+
+// The unshifted keys are the same in 390 and Final. Final consonants are on
+// the left, vowels in the middle and initial consonants on the right.
+fn map_unshifted(key: char) -> Option<Jamo> {
+    let jamo = match key {
+        '1' => Jamo::Final(FinalConsonant::H),
+        '2' => Jamo::Final(FinalConsonant::SS),
+        '3' => Jamo::Final(FinalConsonant::B),
+        'q' => Jamo::Final(FinalConsonant::S),
+        'w' => Jamo::Final(FinalConsonant::L),
+        'a' => Jamo::Final(FinalConsonant::NG),
+        's' => Jamo::Final(FinalConsonant::N),
+        'z' => Jamo::Final(FinalConsonant::M),
+        'x' => Jamo::Final(FinalConsonant::G),
+        '4' => Jamo::Medial(MedialVowel::YO),
+        '5' => Jamo::Medial(MedialVowel::YU),
+        '6' => Jamo::Medial(MedialVowel::YA),
+        '7' => Jamo::Medial(MedialVowel::YE),
+        '8' => Jamo::Medial(MedialVowel::YI),
+        '9' | 'b' => Jamo::Medial(MedialVowel::U),
+        'e' => Jamo::Medial(MedialVowel::YEO),
+        'r' => Jamo::Medial(MedialVowel::AE),
+        't' => Jamo::Medial(MedialVowel::EO),
+        'd' => Jamo::Medial(MedialVowel::I),
+        'f' => Jamo::Medial(MedialVowel::A),
+        'g' => Jamo::Medial(MedialVowel::EU),
+        'c' => Jamo::Medial(MedialVowel::E),
+        'v' | '/' => Jamo::Medial(MedialVowel::O),
+        '0' => Jamo::Initial(InitialConsonant::K),
+        'y' => Jamo::Initial(InitialConsonant::R),
+        'u' => Jamo::Initial(InitialConsonant::D),
+        'i' => Jamo::Initial(InitialConsonant::M),
+        'o' => Jamo::Initial(InitialConsonant::CH),
+        'p' => Jamo::Initial(InitialConsonant::P),
+        'h' => Jamo::Initial(InitialConsonant::N),
+        'j' => Jamo::Initial(InitialConsonant::NG),
+        'k' => Jamo::Initial(InitialConsonant::G),
+        'l' => Jamo::Initial(InitialConsonant::J),
+        ';' => Jamo::Initial(InitialConsonant::B),
+        '\'' => Jamo::Initial(InitialConsonant::T),
+        'n' => Jamo::Initial(InitialConsonant::S),
+        'm' => Jamo::Initial(InitialConsonant::H),
+        _ => return None,
+    };
+    Some(jamo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::type_keys;
+    use crate::Buffer;
+
+    #[test]
+    fn test_no_shift_fallback() {
+        let mut buffer = Buffer::with_layout(Sebeolsik390);
+        buffer.set_shift_fallback(true);
+        assert_eq!(buffer.put('G'), Some('G'));
+        let mut buffer = Buffer::with_layout(SebeolsikFinal);
+        buffer.set_shift_fallback(true);
+        assert_eq!(buffer.put('Y'), Some('Y'));
+        assert_eq!(buffer.to_string(), "");
+    }
+
+    #[test]
+    fn test_390_reference_sequences() {
+        let pairs = vec![
+            ("jfsheamfncj4", "안녕하세요"),
+            ("mfskgw", "한글"),
+            ("kfX", "값"),
+            ("kkf", "까"),
+            ("i9t", "뭐"),
+            ("jvr", "왜"),
+            ("jfs!", "앉"),
+            ("ufwx", "닭"),
+            ("ufw3", "닯"),
+            ("kfxq", "갃"),
+            ("ldF", "짂"),
+            ("jtX", "없"),
+        ];
+        for (keys, expected) in pairs {
+            assert_eq!(
+                type_keys(Buffer::with_layout(Sebeolsik390), keys),
+                expected,
+                "keys: {}",
+                keys
+            );
+        }
+    }
+
+    #[test]
+    fn test_final_reference_sequences() {
+        let pairs = vec![
+            ("jfsheamfncj4", "안녕하세요"),
+            ("jfE", "앉"),
+            ("uf@", "닭"),
+            ("ufD", "닯"),
+            ("kfV", "갃"),
+            ("ld!", "짂"),
+            ("jtX", "없"),
+            ("jdR", "잃"),
+            ("kG", "걔"),
+        ];
+        for (keys, expected) in pairs {
+            assert_eq!(
+                type_keys(Buffer::with_layout(SebeolsikFinal), keys),
+                expected,
+                "keys: {}",
+                keys
+            );
+        }
+    }
+
+    #[test]
+    fn test_final_consonant_does_not_move() {
+        assert_eq!(type_keys(Buffer::with_layout(Sebeolsik390), "kfsf"), "간ㅏ");
+        assert_eq!(type_keys(Buffer::with_layout(Sebeolsik390), "kfk"), "가ㄱ");
+        assert_eq!(type_keys(Buffer::with_layout(Sebeolsik390), "kfsx"), "간ㄱ");
+    }
+
+    #[test]
+    fn test_standalone_final_consonant() {
+        assert_eq!(type_keys(Buffer::with_layout(Sebeolsik390), "xq"), "ㄳ");
+        assert_eq!(type_keys(Buffer::with_layout(Sebeolsik390), "ks"), "ㄱㄴ");
+
+        let mut buffer = Buffer::with_layout(Sebeolsik390);
+        buffer.put('x');
+        buffer.put('q');
+        assert!(buffer.pop().is_some());
+        assert_eq!(buffer.to_string(), "ㄱ");
+        assert!(buffer.pop().is_some());
+        assert_eq!(buffer.to_string(), "");
+    }
+
+    #[test]
+    fn test_unmapped_keys() {
+        let mut buffer = Buffer::with_layout(Sebeolsik390);
+        assert_eq!(buffer.put('@'), Some('@'));
+        assert_eq!(buffer.put(' '), Some(' '));
+        assert_eq!(buffer.put('J'), Some('J'));
+    }
+}
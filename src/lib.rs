@@ -4,6 +4,26 @@
 //! in standard Korean 2-set keyboard. It can output complete Hangul
 //! Syllables as a UTF-32 string. It also allows deletion by Hangul
 //! Jamo.
+//!
+//! Other keyboard layouts, like the 3-set [`Sebeolsik390`] and
+//! [`SebeolsikFinal`], can be used through the [`Layout`] trait.
+//!
+//! Hangul syllables can also be taken apart into their Jamo with [`decompose`]
+//! and put back together with [`compose`].
+//!
+//! Old Hangul with archaic Jamo can be typed with the [`OldHangul`] layout, and
+//! Hangul can be written in Latin letters with [`romanize`]. The other way
+//! around, [`RomajaBuffer`] types Hangul spelled in Latin letters. How Hangul
+//! is pronounced is written by [`pronounce`].
+//!
+//! For search as you type, [`find_chosung`] matches initial consonants(초성)
+//! like "ㅇㄴ" against "안녕", and [`matches_prefix`] matches a syllable still
+//! being composed like "아" against "안녕". Misspellings are found with
+//! [`similarity`], which compares Hangul Jamo by Jamo.
+//!
+//! Text typed with the wrong input mode, like "dkssud" for "안녕", is found
+//! and converted by [`suggest_hangul`] and [`suggest_qwerty`]. For generated
+//! text, [`Particle`] chooses the form of a particle that fits the word.
 //! 
 //! # Example
 //! ```
@@ -31,9 +51,35 @@ use wasm_bindgen::prelude::wasm_bindgen;
 
 mod buffer;
 mod byte;
+mod chosung;
+mod conjoining;
+mod input_mode;
+mod journal;
+mod layout;
+mod particle;
+mod prefix;
+mod pronounce;
+mod reverse;
+mod romaja;
+mod romanize;
+mod similarity;
 mod syllable;
-pub use buffer::Buffer;
+#[cfg(test)]
+mod test_util;
+pub use buffer::{Buffer, Event, Output, PopMode};
 pub use byte::Byte;
+pub use chosung::{chosung, find_chosung, search_chosung};
+pub use conjoining::{from_conjoining, to_conjoining};
+pub use input_mode::{hangul_likelihood, qwerty_likelihood, suggest_hangul, suggest_qwerty};
+pub use layout::{Dubeolsik, Layout, OldHangul, Sebeolsik390, SebeolsikFinal};
+pub use particle::Particle;
+pub use prefix::{matches_prefix, search_prefix};
+pub use pronounce::pronounce;
+pub use reverse::{keystrokes, to_qwerty};
+pub use romaja::RomajaBuffer;
+pub use romanize::{from_yale, romanize, romanize_with, Romanization};
+pub use similarity::{damerau_levenshtein, levenshtein, similarity};
+pub use syllable::{compose, decompose, FinalConsonant, InitialConsonant, Jamo, MedialVowel};
 
 #[cfg(feature = "wasm")]
 #[macro_use]
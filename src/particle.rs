@@ -0,0 +1,212 @@
+use crate::syllable::{decompose, FinalConsonant, InitialConsonant, MedialVowel};
+use std::convert::TryFrom;
+
+/// Korean particles(조사) whose form depends on whether the word before them
+/// ends in a final consonant.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Particle {
+    /// '은' after a final consonant, '는' otherwise.
+    Topic,
+    /// '이' after a final consonant, '가' otherwise.
+    Subject,
+    /// '을' after a final consonant, '를' otherwise.
+    Object,
+    /// '과' after a final consonant, '와' otherwise.
+    Conjunctive,
+    /// '으로' after a final consonant other than 'ㄹ', '로' otherwise.
+    Instrumental,
+}
+
+// How the last syllable of a word is read.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Ending {
+    Vowel,
+    Rieul,
+    Consonant,
+}
+
+// Readings of 0 to 9: 영, 일, 이, 삼, 사, 오, 육, 칠, 팔 and 구.
+const DIGITS: [Ending; 10] = [
+    Ending::Consonant,
+    Ending::Rieul,
+    Ending::Vowel,
+    Ending::Consonant,
+    Ending::Vowel,
+    Ending::Vowel,
+    Ending::Consonant,
+    Ending::Rieul,
+    Ending::Rieul,
+    Ending::Vowel,
+];
+
+impl Particle {
+    /// Chooses the form of the particle that follows the word, by the final
+    /// consonant of its last syllable. Closing brackets, quotes and spaces at
+    /// the end are skipped.
+    ///
+    /// Other characters are read as they are in Korean. A standalone consonant
+    /// is read by its name, like "ㄱ" as "기역". A number is read by its last
+    /// digit, or by the unit of its trailing zeros, like "10" as "십" and
+    /// "1,000" as "천", and the digits after a decimal point are read one by
+    /// one. A Latin letter is read by its name, like "L" as "엘", so a word in
+    /// Latin letters is taken as an abbreviation.
+    ///
+    /// When the word can't be read, both forms are returned, like "은(는)".
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Particle;
+    /// assert_eq!(Particle::Topic.after("사과"), "는");
+    /// assert_eq!(Particle::Subject.after("책"), "이");
+    /// assert_eq!(Particle::Instrumental.after("서울"), "로");
+    /// assert_eq!(Particle::Instrumental.after("부산"), "으로");
+    /// assert_eq!(Particle::Object.after("3"), "을");
+    /// assert_eq!(Particle::Conjunctive.after("URL"), "과");
+    /// assert_eq!(Particle::Topic.after("..."), "은(는)");
+    /// ```
+    pub fn after(self, word: &str) -> &'static str {
+        let (consonant, vowel, both) = match self {
+            Self::Topic => ("은", "는", "은(는)"),
+            Self::Subject => ("이", "가", "이(가)"),
+            Self::Object => ("을", "를", "을(를)"),
+            Self::Conjunctive => ("과", "와", "과(와)"),
+            Self::Instrumental => ("으로", "로", "(으)로"),
+        };
+        match (ending(word), self) {
+            (None, _) => both,
+            (Some(Ending::Rieul), Self::Instrumental) => vowel,
+            (Some(Ending::Vowel), _) => vowel,
+            (Some(_), _) => consonant,
+        }
+    }
+
+    /// Puts the particle after the word, in the form chosen by
+    /// [`Particle::after`].
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::Particle;
+    /// assert_eq!(Particle::Subject.attach("사과"), "사과가");
+    /// assert_eq!(Particle::Topic.attach("\"한글\""), "\"한글\"은");
+    /// ```
+    pub fn attach(self, word: &str) -> String {
+        let mut result = word.to_string();
+        result.push_str(self.after(word));
+        result
+    }
+}
+
+fn ending(word: &str) -> Option<Ending> {
+    let word = word.trim_end_matches(|c: char| {
+        c.is_whitespace() || matches!(c, ')' | ']' | '}' | '"' | '\'' | '”' | '’' | '」' | '』')
+    });
+    let last = word.chars().last()?;
+    if last.is_ascii_digit() {
+        return Some(number_ending(word));
+    }
+    if let Some((_, _, fc)) = decompose(last) {
+        return Some(match fc {
+            None => Ending::Vowel,
+            Some(FinalConsonant::L) => Ending::Rieul,
+            Some(_) => Ending::Consonant,
+        });
+    }
+    // Names of the consonants all end in themselves, like "기역" and "리을".
+    if let Ok(ic) = InitialConsonant::try_from(last) {
+        return Some(match ic {
+            InitialConsonant::R => Ending::Rieul,
+            _ => Ending::Consonant,
+        });
+    }
+    if MedialVowel::try_from(last).is_ok() {
+        return Some(Ending::Vowel);
+    }
+    // Names of the letters: 엘, 알, 엠, 엔, and others like "에이" and "비".
+    match last.to_ascii_lowercase() {
+        'l' | 'r' => Some(Ending::Rieul),
+        'm' | 'n' => Some(Ending::Consonant),
+        'a'..='z' => Some(Ending::Vowel),
+        _ => None,
+    }
+}
+
+fn number_ending(word: &str) -> Ending {
+    let number = word.trim_end_matches(|c: char| c.is_ascii_digit() || c == ',');
+    let digits: Vec<u32> = word[number.len()..]
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect();
+    let last = digits[digits.len() - 1];
+    let zeros = digits.iter().rev().take_while(|d| **d == 0).count();
+    if last != 0 || number.ends_with('.') || zeros == digits.len() {
+        return DIGITS[last as usize];
+    }
+    // 십, 백, 천, 만, 억, 조 and 경.
+    match zeros {
+        12..=15 => Ending::Vowel,
+        _ => Ending::Consonant,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hangul() {
+        assert_eq!("은", Particle::Topic.after("책"));
+        assert_eq!("는", Particle::Topic.after("나무"));
+        assert_eq!("가", Particle::Subject.after("나무"));
+        assert_eq!("를", Particle::Object.after("나무"));
+        assert_eq!("와", Particle::Conjunctive.after("나무"));
+        assert_eq!("과", Particle::Conjunctive.after("책"));
+        assert_eq!("으로", Particle::Instrumental.after("책"));
+        assert_eq!("로", Particle::Instrumental.after("나무"));
+        assert_eq!("로", Particle::Instrumental.after("연필"));
+        assert_eq!("을", Particle::Object.after("연필"));
+        assert_eq!("이", Particle::Subject.after("(닭)"));
+        assert_eq!("은", Particle::Topic.after("「세종」 "));
+    }
+
+    #[test]
+    fn test_jamo() {
+        assert_eq!("이", Particle::Subject.after("ㄱ"));
+        assert_eq!("로", Particle::Instrumental.after("ㄹ"));
+        assert_eq!("가", Particle::Subject.after("ㅏ"));
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!("이", Particle::Subject.after("1"));
+        assert_eq!("로", Particle::Instrumental.after("1"));
+        assert_eq!("가", Particle::Subject.after("2"));
+        assert_eq!("으로", Particle::Instrumental.after("6"));
+        assert_eq!("를", Particle::Object.after("9"));
+        assert_eq!("은", Particle::Topic.after("0"));
+        assert_eq!("은", Particle::Topic.after("10"));
+        assert_eq!("은", Particle::Topic.after("1,000"));
+        assert_eq!("는", Particle::Topic.after("3,000,000,000,000"));
+        assert_eq!("은", Particle::Topic.after("2.0"));
+        assert_eq!("는", Particle::Topic.after("버전 3.5"));
+        assert_eq!("은", Particle::Topic.after("제1000"));
+    }
+
+    #[test]
+    fn test_latin_letters() {
+        assert_eq!("는", Particle::Topic.after("API"));
+        assert_eq!("로", Particle::Instrumental.after("HTML"));
+        assert_eq!("을", Particle::Object.after("RAM"));
+        assert_eq!("이", Particle::Subject.after("DVD-ROM"));
+        assert_eq!("가", Particle::Subject.after("pdf"));
+        assert_eq!("이", Particle::Subject.after("PR"));
+        assert_eq!("은", Particle::Topic.after("PR"));
+        assert_eq!("로", Particle::Instrumental.after("PR"));
+    }
+
+    #[test]
+    fn test_unreadable() {
+        assert_eq!("이(가)", Particle::Subject.after(""));
+        assert_eq!("(으)로", Particle::Instrumental.after("?!"));
+        assert_eq!("을(를)", Particle::Object.after("漢字"));
+    }
+}
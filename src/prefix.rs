@@ -0,0 +1,129 @@
+use crate::syllable::{decompose, FinalConsonant, MedialVowel};
+use std::convert::TryFrom;
+
+/// Tells if the candidate can be what the query is typed into. The syllables
+/// of the query must be in the candidate as they are, except the last one,
+/// which may be still composed by a [`Buffer`](crate::Buffer). It only has to
+/// be typed by the first keys of the rest of the candidate, compared Jamo by
+/// Jamo with compound Jamo taken apart.
+///
+/// So "아" matches "안녕", and "앉" matches "앉다" as well as "안지", where
+/// 'ㅈ' of 'ㄵ' moves to the next syllable when 'ㅣ' is typed.
+///
+/// # Example
+/// ```
+/// use sejong::{matches_prefix, Buffer};
+/// let mut buf = Buffer::default();
+/// for key in "dksw".chars() {
+///     buf.put(key);
+/// }
+/// assert_eq!(buf.to_string(), "앉");
+/// assert!(matches_prefix(&buf.to_string(), "앉다"));
+/// assert!(matches_prefix(&buf.to_string(), "안지"));
+/// assert!(!matches_prefix(&buf.to_string(), "안녕"));
+/// ```
+pub fn matches_prefix(query: &str, candidate: &str) -> bool {
+    let mut query = query.chars();
+    let last = match query.next_back() {
+        Some(last) => last,
+        None => return true,
+    };
+    let mut candidate = candidate.chars();
+    if !query.all(|q| candidate.next() == Some(q)) {
+        return false;
+    }
+    let open = jamo(last);
+    let rest: Vec<char> = candidate.flat_map(jamo).take(open.len()).collect();
+    rest == open
+}
+
+/// Finds the candidates that the query matches with [`matches_prefix`], in the
+/// order given.
+///
+/// # Example
+/// ```
+/// use sejong::search_prefix;
+/// let candidates = ["안녕", "아기", "가방", "앉다"];
+/// assert_eq!(search_prefix("아", candidates), ["안녕", "아기", "앉다"]);
+/// assert_eq!(search_prefix("안", candidates), ["안녕", "앉다"]);
+/// ```
+pub fn search_prefix<'a, I>(query: &str, candidates: I) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    candidates
+        .into_iter()
+        .filter(|candidate| matches_prefix(query, candidate))
+        .collect()
+}
+
+// The Jamo a character is typed with, in Hangul Compatibility Jamo. Compound
+// vowels and final consonants are taken apart.
+fn jamo(c: char) -> Vec<char> {
+    let mut result = Vec::new();
+    if let Some((ic, mv, fc)) = decompose(c) {
+        result.push(char::from(ic));
+        push_vowel(&mut result, mv);
+        if let Some(fc) = fc {
+            push_final(&mut result, fc);
+        }
+    } else if let Ok(mv) = MedialVowel::try_from(c) {
+        push_vowel(&mut result, mv);
+    } else if let Ok(fc) = FinalConsonant::try_from(c) {
+        push_final(&mut result, fc);
+    } else {
+        result.push(c);
+    }
+    result
+}
+
+fn push_vowel(result: &mut Vec<char>, mv: MedialVowel) {
+    let (first, second) = mv.split();
+    result.push(char::from(first));
+    result.extend(second.map(char::from));
+}
+
+fn push_final(result: &mut Vec<char>, fc: FinalConsonant) {
+    let (first, second) = fc.split();
+    result.push(char::from(first));
+    result.extend(second.map(char::from));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jamo() {
+        assert_eq!(vec!['ㅇ', 'ㅏ', 'ㄴ', 'ㅈ'], jamo('앉'));
+        assert_eq!(vec!['ㄱ', 'ㅗ', 'ㅏ'], jamo('과'));
+        assert_eq!(vec!['ㄲ'], jamo('ㄲ'));
+        assert_eq!(vec!['ㄱ', 'ㅅ'], jamo('ㄳ'));
+        assert_eq!(vec!['ㅜ', 'ㅓ'], jamo('ㅝ'));
+        assert_eq!(vec!['a'], jamo('a'));
+    }
+
+    #[test]
+    fn test_open_last_syllable() {
+        assert!(matches_prefix("", "안녕"));
+        assert!(matches_prefix("ㅇ", "안녕"));
+        assert!(matches_prefix("아", "안녕"));
+        assert!(matches_prefix("안", "안녕"));
+        assert!(matches_prefix("안ㄴ", "안녕"));
+        assert!(matches_prefix("안녀", "안녕"));
+        assert!(matches_prefix("고", "과자"));
+        assert!(matches_prefix("각", "가게"));
+        assert!(matches_prefix("갃", "각시"));
+        assert!(!matches_prefix("아", "ㅇ"));
+        assert!(!matches_prefix("가", "과자"));
+        assert!(!matches_prefix("ㄱ", "ㄲ"));
+    }
+
+    #[test]
+    fn test_finished_syllables() {
+        assert!(matches_prefix("안녕하", "안녕하세요"));
+        assert!(!matches_prefix("아녀", "안녕"));
+        assert!(!matches_prefix("안녕하세요!", "안녕하세요"));
+        assert!(matches_prefix("a가", "a각"));
+    }
+}
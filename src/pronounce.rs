@@ -0,0 +1,307 @@
+use crate::syllable::{compose, decompose, FinalConsonant, InitialConsonant, MedialVowel};
+use std::convert::{TryFrom, TryInto};
+
+type Decomposed = (InitialConsonant, MedialVowel, Option<FinalConsonant>);
+
+/// Writes Hangul the way it is pronounced, by the Standard Pronunciation of
+/// Korean(표준 발음법). Characters other than modern Hangul syllables are kept
+/// as they are, and end a word.
+///
+/// These changes are made where a final consonant meets the next syllable in
+/// the same word:
+///
+/// * Liaison(연음): a final consonant followed by 'ㅇ' is carried over to the
+///   next syllable, like "옷이" into "오시". Only the second half of a compound
+///   final consonant is carried over, like "닭을" into "달글".
+/// * Palatalization(구개음화): 'ㄷ' and 'ㅌ' carried over to '이' become 'ㅈ'
+///   and 'ㅊ', like "같이" into "가치".
+/// * Aspiration(격음화): 'ㅎ' and a plain consonant next to each other become
+///   the aspirated consonant, like "좋고" into "조코" and "입학" into "이팍".
+///   'ㅎ' is not heard before a vowel.
+/// * Tensification(경음화): a plain consonant after 'ㄱ', 'ㄷ' or 'ㅂ' becomes
+///   tense, like "국밥" into "국빱", and so does one after a compound final
+///   consonant of a stem, like "앉다" into "안따".
+/// * Nasalization(비음화): 'ㄱ', 'ㄷ' and 'ㅂ' before 'ㄴ' or 'ㅁ' become 'ㅇ', 'ㄴ'
+///   and 'ㅁ', like "국물" into "궁물", and 'ㄹ' after other consonants than
+///   'ㄴ' and 'ㄹ' becomes 'ㄴ', like "협력" into "혐녁".
+/// * Liquidization(유음화): 'ㄴ' next to 'ㄹ' becomes 'ㄹ', like "신라" into
+///   "실라".
+///
+/// A final consonant not followed by a vowel is pronounced by its
+/// representative sound, like "닭" into "닥" and "옷" into "옫".
+///
+/// Every syllable boundary is read as the one between a stem and its ending,
+/// since words are not analyzed. Rules that depend on the meaning of the
+/// word, like the added 'ㄴ' in "꽃잎"(꼰닙), are not applied.
+///
+/// # Example
+/// ```
+/// use sejong::pronounce;
+/// assert_eq!(pronounce("국물"), "궁물");
+/// assert_eq!(pronounce("같이"), "가치");
+/// assert_eq!(pronounce("신라"), "실라");
+/// assert_eq!(pronounce("좋고 국밥"), "조코 국빱");
+/// ```
+pub fn pronounce(hangul: &str) -> String {
+    let mut syllables: Vec<Result<Decomposed, char>> =
+        hangul.chars().map(|c| decompose(c).ok_or(c)).collect();
+    for i in 0..syllables.len() {
+        let fc = match syllables[i] {
+            Ok((_, _, Some(fc))) => fc,
+            _ => continue,
+        };
+        let fc = match syllables.get_mut(i + 1) {
+            Some(Ok((ic, mv, _))) => {
+                let (fc, next) = pronounce_boundary(fc, *ic, *mv, true);
+                *ic = next;
+                fc
+            }
+            _ => Some(representative(fc)),
+        };
+        if let Ok((_, _, before)) = &mut syllables[i] {
+            *before = fc;
+        }
+    }
+    syllables
+        .into_iter()
+        .flat_map(|syllable| match syllable {
+            Ok((ic, mv, fc)) => compose(ic, mv, fc),
+            Err(c) => Some(c),
+        })
+        .collect()
+}
+
+// Pronounces a final consonant and the initial consonant after it. Without
+// `tensify`, consonants that would become tense are left plain, as the
+// Revised Romanization writes them.
+pub(crate) fn pronounce_boundary(
+    fc: FinalConsonant,
+    ic: InitialConsonant,
+    mv: MedialVowel,
+    tensify: bool,
+) -> (Option<FinalConsonant>, InitialConsonant) {
+    // 'ㅎ' in the final consonant.
+    let without_h = match fc {
+        FinalConsonant::H => Some(None),
+        FinalConsonant::NH => Some(Some(FinalConsonant::N)),
+        FinalConsonant::LH => Some(Some(FinalConsonant::L)),
+        _ => None,
+    };
+    if let Some(rest) = without_h {
+        match (rest, ic) {
+            (_, InitialConsonant::G) => return (rest, InitialConsonant::K),
+            (_, InitialConsonant::D) => return (rest, InitialConsonant::T),
+            (_, InitialConsonant::J) => return (rest, InitialConsonant::CH),
+            (_, InitialConsonant::S) => return (rest, tense(ic, tensify)),
+            (None, InitialConsonant::N) => return (Some(FinalConsonant::N), ic),
+            (Some(FinalConsonant::L), InitialConsonant::N) => return (rest, InitialConsonant::R),
+            (Some(_), InitialConsonant::N) => return (rest, ic),
+            (None, InitialConsonant::NG) => return (None, ic),
+            (Some(rest), InitialConsonant::NG) => {
+                return (None, InitialConsonant::try_from(rest).unwrap_or(ic))
+            }
+            _ => {}
+        }
+    }
+
+    if ic == InitialConsonant::NG {
+        let split_result: Result<(FinalConsonant, InitialConsonant), FinalConsonant> =
+            fc.try_into();
+        return match split_result {
+            Ok((first, InitialConsonant::S)) => (Some(first), tense(InitialConsonant::S, tensify)),
+            Ok((first, second)) => (Some(first), palatalize(second, mv)),
+            Err(fc) => match InitialConsonant::try_from(fc) {
+                Ok(InitialConsonant::NG) | Err(_) => (Some(fc), ic),
+                Ok(moved) => (None, palatalize(moved, mv)),
+            },
+        };
+    }
+
+    if ic == InitialConsonant::H {
+        let (rest, last) = match fc.split() {
+            (first, Some(second)) => (Some(first), second),
+            (fc, None) => (None, fc),
+        };
+        if let Some(aspirated) = aspirate(last) {
+            return (rest, palatalize(aspirated, mv));
+        }
+    }
+
+    // Compound final consonants other than those of 'ㄱ', 'ㄷ' and 'ㅂ' sounds
+    // are only found in stems, which tense the endings after them.
+    let mut ic = ic;
+    let tensing = matches!(
+        representative(fc),
+        FinalConsonant::G | FinalConsonant::D | FinalConsonant::B
+    ) || matches!(
+        fc,
+        FinalConsonant::NJ | FinalConsonant::LM | FinalConsonant::LB | FinalConsonant::LT
+    );
+    if tensing {
+        ic = tense(ic, tensify);
+    }
+    let mut fc = match (fc, ic) {
+        (FinalConsonant::LG, InitialConsonant::KK | InitialConsonant::G) => FinalConsonant::L,
+        _ => representative(fc),
+    };
+    let stop = matches!(
+        fc,
+        FinalConsonant::G | FinalConsonant::D | FinalConsonant::B
+    );
+    match ic {
+        InitialConsonant::N | InitialConsonant::M if stop => fc = nasalize(fc),
+        InitialConsonant::R => match fc {
+            FinalConsonant::L => {}
+            FinalConsonant::N => fc = FinalConsonant::L,
+            _ => {
+                fc = nasalize(fc);
+                ic = InitialConsonant::N;
+            }
+        },
+        InitialConsonant::N if fc == FinalConsonant::L => ic = InitialConsonant::R,
+        _ => {}
+    }
+    (Some(fc), ic)
+}
+
+// The sound a final consonant makes at the end of a syllable(받침의 대표음).
+fn representative(fc: FinalConsonant) -> FinalConsonant {
+    match fc {
+        FinalConsonant::KK | FinalConsonant::GS | FinalConsonant::LG | FinalConsonant::K => {
+            FinalConsonant::G
+        }
+        FinalConsonant::NJ | FinalConsonant::NH => FinalConsonant::N,
+        FinalConsonant::S
+        | FinalConsonant::SS
+        | FinalConsonant::J
+        | FinalConsonant::CH
+        | FinalConsonant::T
+        | FinalConsonant::H => FinalConsonant::D,
+        FinalConsonant::LB | FinalConsonant::LS | FinalConsonant::LT | FinalConsonant::LH => {
+            FinalConsonant::L
+        }
+        FinalConsonant::LM => FinalConsonant::M,
+        FinalConsonant::BS | FinalConsonant::LP | FinalConsonant::P => FinalConsonant::B,
+        fc => fc,
+    }
+}
+
+// The aspirated consonant a final consonant makes with 'ㅎ' after it.
+fn aspirate(fc: FinalConsonant) -> Option<InitialConsonant> {
+    match fc {
+        FinalConsonant::J | FinalConsonant::CH => return Some(InitialConsonant::CH),
+        _ => {}
+    }
+    match representative(fc) {
+        FinalConsonant::G => Some(InitialConsonant::K),
+        FinalConsonant::D => Some(InitialConsonant::T),
+        FinalConsonant::B => Some(InitialConsonant::P),
+        _ => None,
+    }
+}
+
+fn tense(ic: InitialConsonant, tensify: bool) -> InitialConsonant {
+    match tensify {
+        true => InitialConsonant::try_from((ic, ic)).unwrap_or(ic),
+        false => ic,
+    }
+}
+
+fn nasalize(fc: FinalConsonant) -> FinalConsonant {
+    match fc {
+        FinalConsonant::G => FinalConsonant::NG,
+        FinalConsonant::D => FinalConsonant::N,
+        FinalConsonant::B => FinalConsonant::M,
+        fc => fc,
+    }
+}
+
+fn palatalize(ic: InitialConsonant, mv: MedialVowel) -> InitialConsonant {
+    match (ic, mv) {
+        (InitialConsonant::D, MedialVowel::I) => InitialConsonant::J,
+        (InitialConsonant::T, MedialVowel::I) => InitialConsonant::CH,
+        (ic, _) => ic,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_representative_sounds() {
+        assert_eq!("닥", pronounce("닭"));
+        assert_eq!("옫", pronounce("옷"));
+        assert_eq!("갑", pronounce("값"));
+        assert_eq!("안따", pronounce("앉다"));
+        assert_eq!("부억 안", pronounce("부엌 안"));
+    }
+
+    #[test]
+    fn test_liaison() {
+        assert_eq!("오시", pronounce("옷이"));
+        assert_eq!("달글", pronounce("닭을"));
+        assert_eq!("갑쓸", pronounce("값을"));
+        assert_eq!("바께", pronounce("밖에"));
+        assert_eq!("이써요", pronounce("있어요"));
+        assert_eq!("강아지", pronounce("강아지"));
+    }
+
+    #[test]
+    fn test_palatalization() {
+        assert_eq!("구지", pronounce("굳이"));
+        assert_eq!("가치", pronounce("같이"));
+        assert_eq!("다치다", pronounce("닫히다"));
+        assert_eq!("할치다", pronounce("핥이다"));
+    }
+
+    #[test]
+    fn test_aspiration() {
+        assert_eq!("조코", pronounce("좋고"));
+        assert_eq!("안코", pronounce("않고"));
+        assert_eq!("이팍", pronounce("입학"));
+        assert_eq!("머키다", pronounce("먹히다"));
+        assert_eq!("안치다", pronounce("앉히다"));
+        assert_eq!("마텽", pronounce("맏형"));
+        assert_eq!("조아", pronounce("좋아"));
+        assert_eq!("아는", pronounce("않은"));
+        assert_eq!("시러", pronounce("싫어"));
+        assert_eq!("다쏘", pronounce("닿소"));
+    }
+
+    #[test]
+    fn test_tensification() {
+        assert_eq!("국빱", pronounce("국밥"));
+        assert_eq!("학꾜", pronounce("학교"));
+        assert_eq!("말께", pronounce("맑게"));
+        assert_eq!("널께", pronounce("넓게"));
+        assert_eq!("점따", pronounce("젊다"));
+        assert_eq!("옫깜", pronounce("옷감"));
+    }
+
+    #[test]
+    fn test_nasalization() {
+        assert_eq!("궁물", pronounce("국물"));
+        assert_eq!("반는", pronounce("받는"));
+        assert_eq!("밤만", pronounce("밥만"));
+        assert_eq!("혐녁", pronounce("협력"));
+        assert_eq!("담녁", pronounce("담력"));
+        assert_eq!("강능", pronounce("강릉"));
+        assert_eq!("뱅니", pronounce("백리"));
+        assert_eq!("논는", pronounce("놓는"));
+    }
+
+    #[test]
+    fn test_liquidization() {
+        assert_eq!("실라", pronounce("신라"));
+        assert_eq!("칼랄", pronounce("칼날"));
+        assert_eq!("뚤른", pronounce("뚫는"));
+        assert_eq!("할른", pronounce("핥는"));
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!("궁물 이써요?", pronounce("국물 있어요?"));
+        assert_eq!("ㄱ 한국", pronounce("ㄱ 한국"));
+    }
+}
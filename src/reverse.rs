@@ -0,0 +1,79 @@
+use crate::byte::Byte;
+use crate::syllable::Syllable;
+use std::convert::TryFrom;
+
+/// Returns the [`Byte`]s that type `c` on a 2-set keyboard, compound vowels
+/// and compound final consonants split into their halves. Returns `None` when
+/// `c` is neither a modern Hangul syllable nor a Hangul Compatibility Jamo.
+///
+/// # Example
+/// ```
+/// use sejong::keystrokes;
+/// let bytes: Vec<u8> = keystrokes('괜').unwrap().into_iter().map(|b| b as u8).collect();
+/// assert_eq!(bytes, b"rhos");
+/// let bytes: Vec<u8> = keystrokes('ㄺ').unwrap().into_iter().map(|b| b as u8).collect();
+/// assert_eq!(bytes, b"fr");
+/// assert!(keystrokes('a').is_none());
+/// ```
+pub fn keystrokes(c: char) -> Option<Vec<Byte>> {
+    Syllable::try_from(c).ok().map(|syl| syl.bytes())
+}
+
+/// Converts Hangul back to the QWERTY keys that type it on a 2-set keyboard.
+/// This is the inverse of [`Buffer`](crate::Buffer), useful for text typed
+/// with the wrong input mode. Characters other than Hangul are kept as they are.
+///
+/// # Example
+/// ```
+/// use sejong::to_qwerty;
+/// assert_eq!(to_qwerty("안녕"), "dkssud");
+/// assert_eq!(to_qwerty("닭 3마리"), "ekfr 3akfl");
+/// ```
+pub fn to_qwerty(hangul: &str) -> String {
+    let mut result = String::with_capacity(hangul.len());
+    for c in hangul.chars() {
+        match keystrokes(c) {
+            Some(bytes) => result.extend(bytes.into_iter().map(|b| b as u8 as char)),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{all_syllables, type_keys};
+    use crate::Buffer;
+
+    #[test]
+    fn test_round_trip_every_syllable() {
+        for syllable in all_syllables() {
+            let keys = to_qwerty(&syllable.to_string());
+            assert_eq!(
+                type_keys(Buffer::default(), &keys),
+                syllable.to_string(),
+                "keys: {}",
+                keys
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip_consecutive_syllables() {
+        let text: String = all_syllables().collect();
+        assert_eq!(type_keys(Buffer::default(), &to_qwerty(&text)), text);
+    }
+
+    #[test]
+    fn test_compatibility_jamo() {
+        assert_eq!(to_qwerty("ㄱㄲㄳㅘㅢ"), "rRrthkml");
+        assert_eq!(type_keys(Buffer::default(), &to_qwerty("ㅃ")), "ㅃ");
+    }
+
+    #[test]
+    fn test_keep_other_characters() {
+        assert_eq!(to_qwerty("Rust 좋아!"), "Rust whgdk!");
+        assert_eq!(to_qwerty(""), "");
+    }
+}
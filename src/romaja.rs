@@ -0,0 +1,224 @@
+use crate::buffer::Buffer;
+use crate::romanize::REVISED_VOWELS;
+use crate::syllable::{InitialConsonant, Jamo, MedialVowel};
+use std::fmt;
+
+// Longest first, so that a shorter spelling never cuts a longer one. 'ㅇ' is
+// only spelled at the end of a syllable.
+const CONSONANTS: [(&str, InitialConsonant); 20] = [
+    ("kk", InitialConsonant::KK),
+    ("tt", InitialConsonant::TT),
+    ("pp", InitialConsonant::PP),
+    ("ss", InitialConsonant::SS),
+    ("jj", InitialConsonant::JJ),
+    ("ch", InitialConsonant::CH),
+    ("ng", InitialConsonant::NG),
+    ("g", InitialConsonant::G),
+    ("n", InitialConsonant::N),
+    ("d", InitialConsonant::D),
+    ("r", InitialConsonant::R),
+    ("l", InitialConsonant::R),
+    ("m", InitialConsonant::M),
+    ("b", InitialConsonant::B),
+    ("s", InitialConsonant::S),
+    ("j", InitialConsonant::J),
+    ("k", InitialConsonant::K),
+    ("t", InitialConsonant::T),
+    ("p", InitialConsonant::P),
+    ("h", InitialConsonant::H),
+];
+
+/// A buffer that takes Hangul spelled in the Revised Romanization of Korean,
+/// for those who don't know a Korean keyboard layout. Typing "annyeong" makes
+/// "안녕".
+///
+/// The keys are lowercase letters, and an apostrophe or a hyphen. Letters are
+/// read into Jamo longest first, so "yeo" is 'ㅕ' rather than 'ㅛ' and 'ㅓ', and
+/// "kk" is 'ㄲ'. The Jamo are composed by [`Buffer`], where a final consonant
+/// followed by a vowel starts the next syllable. Where the spelling alone
+/// can't tell the syllables apart, these rules decide:
+///
+/// * A vowel with no consonant before it starts a syllable with 'ㅇ'.
+/// * "ng" is 'ㅇ', unless a vowel follows it. Then it is 'ㄴ' and 'ㄱ', so
+///   "hangeul" is "한글".
+/// * "k", "t" and "p" are 'ㄱ', 'ㄷ' and 'ㅂ' when no vowel follows them, and
+///   'ㅋ', 'ㅌ' and 'ㅍ' otherwise, so "hakgyo" is "학교".
+/// * An apostrophe or a hyphen ends the syllable, so "gang'aji" is "강아지" and
+///   "an'a" is "안아", where "gangaji" and "ana" would be "간가지" and "아나".
+///
+/// Letters that don't spell any Jamo yet, like a "y" waiting for its vowel,
+/// are written out as they are.
+///
+/// # Example
+/// ```
+/// use sejong::RomajaBuffer;
+/// let mut buf = RomajaBuffer::default();
+/// for key in "hange".chars() {
+///     buf.put(key);
+/// }
+/// assert_eq!(buf.to_string(), "한게");
+/// buf.put('u');
+/// buf.put('l');
+/// assert_eq!(buf.to_string(), "한글");
+/// buf.pop();
+/// assert_eq!(buf.out(), "한그");
+/// ```
+#[derive(Clone, Default)]
+pub struct RomajaBuffer {
+    latin: String,
+}
+
+impl RomajaBuffer {
+    /// Put a key into the buffer. Returns `Some(key)` when the key is not used
+    /// to spell Hangul, and `None` when it is accepted.
+    pub fn put<T>(&mut self, key: T) -> Option<T>
+    where
+        T: Copy + Into<char>,
+    {
+        match key.into() {
+            c @ ('a'..='e' | 'g'..='p' | 'r'..='u' | 'w' | 'y' | '\'' | '-') => {
+                self.latin.push(c);
+                None
+            }
+            _ => Some(key),
+        }
+    }
+
+    /// Removes the last key put. Returns `None` when the buffer is empty.
+    pub fn pop(&mut self) -> Option<()> {
+        self.latin.pop().map(|_| ())
+    }
+
+    /// Output the buffer as a UTF-32 string. Calling this method clears the
+    /// buffer. If buffer needs to be preserved, use `to_string()`.
+    pub fn out(&mut self) -> String {
+        let result = self.to_string();
+        self.latin.clear();
+        result
+    }
+
+    fn compose(&self) -> Buffer {
+        let mut buffer = Buffer::default();
+        let mut after_consonant = false;
+        let mut rest = self.latin.as_str();
+        while let Some(c) = rest.chars().next() {
+            if let Some((spelling, mv)) = read_vowel(rest) {
+                if !after_consonant {
+                    buffer.put_jamo(Jamo::Consonant(InitialConsonant::NG));
+                }
+                buffer.put_jamo(Jamo::Vowel(mv));
+                after_consonant = false;
+                rest = &rest[spelling.len()..];
+            } else if let Some((spelling, ic)) =
+                CONSONANTS.iter().find(|(s, _)| rest.starts_with(*s))
+            {
+                let vowel_follows = read_vowel(&rest[spelling.len()..]).is_some();
+                let (ic, len) = match (ic, vowel_follows) {
+                    (InitialConsonant::NG, true) => (InitialConsonant::N, 1),
+                    (InitialConsonant::K, false) => (InitialConsonant::G, 1),
+                    (InitialConsonant::T, false) => (InitialConsonant::D, 1),
+                    (InitialConsonant::P, false) => (InitialConsonant::B, 1),
+                    (ic, _) => (*ic, spelling.len()),
+                };
+                buffer.put_jamo(Jamo::Consonant(ic));
+                after_consonant = true;
+                rest = &rest[len..];
+            } else {
+                match c {
+                    '\'' | '-' => buffer.finish(),
+                    _ => buffer.put_literal(c),
+                }
+                after_consonant = false;
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        buffer
+    }
+}
+
+fn read_vowel(latin: &str) -> Option<(&'static str, MedialVowel)> {
+    REVISED_VOWELS
+        .iter()
+        .zip(MedialVowel::ALL.iter())
+        .filter(|(spelling, _)| latin.starts_with(*spelling))
+        .max_by_key(|(spelling, _)| spelling.len())
+        .map(|(spelling, mv)| (*spelling, *mv))
+}
+
+/// Output the buffer as a UTF-32 string with `to_string()`. It doesn't clear
+/// the buffer.
+impl fmt::Display for RomajaBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.compose().fmt(f)
+    }
+}
+
+impl From<RomajaBuffer> for String {
+    fn from(buffer: RomajaBuffer) -> Self {
+        buffer.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::type_keys;
+
+    #[test]
+    fn test_words() {
+        assert_eq!("안녕", type_keys(RomajaBuffer::default(), "annyeong"));
+        assert_eq!(
+            "대한민국",
+            type_keys(RomajaBuffer::default(), "daehanminguk")
+        );
+        assert_eq!("서울", type_keys(RomajaBuffer::default(), "seoul"));
+        assert_eq!(
+            "괜차나요",
+            type_keys(RomajaBuffer::default(), "gwaenchanayo")
+        );
+        assert_eq!("빨리", type_keys(RomajaBuffer::default(), "ppalli"));
+        assert_eq!("밥", type_keys(RomajaBuffer::default(), "bap"));
+        assert_eq!("칼", type_keys(RomajaBuffer::default(), "kal"));
+        assert_eq!("닭", type_keys(RomajaBuffer::default(), "dalk"));
+        assert_eq!("외", type_keys(RomajaBuffer::default(), "oe"));
+        assert_eq!("얘기", type_keys(RomajaBuffer::default(), "yaegi"));
+    }
+
+    #[test]
+    fn test_syllable_boundaries() {
+        assert_eq!("한글", type_keys(RomajaBuffer::default(), "hangeul"));
+        assert_eq!("학교", type_keys(RomajaBuffer::default(), "hakgyo"));
+        assert_eq!("간가지", type_keys(RomajaBuffer::default(), "gangaji"));
+        assert_eq!("강아지", type_keys(RomajaBuffer::default(), "gang'aji"));
+        assert_eq!("아나", type_keys(RomajaBuffer::default(), "ana"));
+        assert_eq!("안아", type_keys(RomajaBuffer::default(), "an-a"));
+        assert_eq!("오에", type_keys(RomajaBuffer::default(), "o'e"));
+    }
+
+    #[test]
+    fn test_incomplete_letters() {
+        assert_eq!("y", type_keys(RomajaBuffer::default(), "y"));
+        assert_eq!("ㄱw", type_keys(RomajaBuffer::default(), "gw"));
+        assert_eq!("c", type_keys(RomajaBuffer::default(), "c"));
+        assert_eq!("ㅇ", type_keys(RomajaBuffer::default(), "ng"));
+    }
+
+    #[test]
+    fn test_put_and_pop() {
+        let mut buffer = RomajaBuffer::default();
+        for key in "annye".chars() {
+            buffer.put(key);
+        }
+        assert_eq!(buffer.to_string(), "안녜");
+        buffer.put('o');
+        assert_eq!(buffer.to_string(), "안녀");
+        assert_eq!(buffer.put('f'), Some('f'));
+        assert_eq!(buffer.put('A'), Some('A'));
+        assert_eq!(buffer.put(b'1'), Some(b'1'));
+        buffer.pop();
+        buffer.pop();
+        assert_eq!(buffer.to_string(), "안ㄴy");
+        assert_eq!(buffer.out(), "안ㄴy");
+        assert_eq!(buffer.pop(), None);
+    }
+}
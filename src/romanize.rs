@@ -0,0 +1,459 @@
+use crate::pronounce::pronounce_boundary;
+use crate::syllable::{compose, decompose, FinalConsonant, InitialConsonant, MedialVowel};
+
+/// Systems to write Hangul in Latin letters, used by [`romanize_with`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Romanization {
+    /// The Revised Romanization of Korean, the official system of South Korea.
+    #[default]
+    Revised,
+    /// McCune–Reischauer, with breves on 'ㅓ'(ŏ) and 'ㅡ'(ŭ) and apostrophes on
+    /// aspirated consonants. Plain consonants are voiced between voiced sounds,
+    /// and "n'g" tells 'ㄴ' followed by 'ㄱ' from 'ㅇ'. Words are spelled as
+    /// they are pronounced, with the sound changes written as in `Revised`,
+    /// like "종로" as "chongno" and "신라" as "silla".
+    McCuneReischauer,
+    /// Yale, which spells every Jamo the same wherever it is, so it can be read
+    /// back with [`from_yale`]. A period separates syllables that would be read
+    /// back differently otherwise.
+    Yale,
+}
+
+struct Scheme {
+    initials: [&'static str; 19],
+    voiced_initials: [&'static str; 19],
+    vowels: [&'static str; 21],
+    finals: [&'static str; 27],
+    mccune_reischauer: bool,
+}
+
+// Tables are indexed by the order of `InitialConsonant`, `MedialVowel` and
+// `FinalConsonant`, which starts at 1. A final consonant is romanized by the
+// sound it makes at the end of a syllable.
+const REVISED: Scheme = Scheme {
+    initials: [
+        "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t",
+        "p", "h",
+    ],
+    voiced_initials: [
+        "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t",
+        "p", "h",
+    ],
+    vowels: REVISED_VOWELS,
+    finals: [
+        "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+        "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+    ],
+    mccune_reischauer: false,
+};
+
+pub(crate) const REVISED_VOWELS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+
+const MCCUNE_REISCHAUER: Scheme = Scheme {
+    initials: [
+        "k", "kk", "n", "t", "tt", "r", "m", "p", "pp", "s", "ss", "", "ch", "tch", "ch'", "k'",
+        "t'", "p'", "h",
+    ],
+    voiced_initials: [
+        "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "tch", "ch'", "k'",
+        "t'", "p'", "h",
+    ],
+    vowels: [
+        "a", "ae", "ya", "yae", "ŏ", "e", "yŏ", "ye", "o", "wa", "wae", "oe", "yo", "u", "wŏ",
+        "we", "wi", "yu", "ŭ", "ŭi", "i",
+    ],
+    finals: REVISED.finals,
+    mccune_reischauer: true,
+};
+
+const YALE_INITIALS: [&str; 19] = [
+    "k", "kk", "n", "t", "tt", "l", "m", "p", "pp", "s", "ss", "", "c", "cc", "ch", "kh", "th",
+    "ph", "h",
+];
+
+const YALE_VOWELS: [&str; 21] = [
+    "a", "ay", "ya", "yay", "e", "ey", "ye", "yey", "o", "wa", "way", "oy", "yo", "wu", "we",
+    "wey", "wi", "yu", "u", "uy", "i",
+];
+
+const YALE_FINALS: [&str; 27] = [
+    "k", "kk", "ks", "n", "nc", "nh", "t", "l", "lk", "lm", "lp", "ls", "lth", "lph", "lh", "m",
+    "p", "ps", "s", "ss", "ng", "c", "ch", "kh", "th", "ph", "h",
+];
+
+// Longest first, so that a shorter spelling never cuts a longer one.
+const YALE_CONSONANT_TOKENS: [&str; 19] = [
+    "kk", "kh", "ng", "tt", "th", "pp", "ph", "ss", "cc", "ch", "k", "n", "t", "l", "m", "p", "s",
+    "c", "h",
+];
+const YALE_VOWEL_TOKENS: [&str; 21] = [
+    "yay", "yey", "way", "wey", "ay", "ya", "ey", "ye", "wa", "oy", "yo", "wu", "we", "wi", "yu",
+    "uy", "a", "e", "o", "u", "i",
+];
+
+/// Romanizes Hangul in the Revised Romanization of Korean. Characters other
+/// than modern Hangul syllables are kept as they are, and end a word.
+///
+/// A consonant is romanized by where it is heard. A final consonant followed
+/// by a vowel is carried over to the next syllable, where 'ㄱ' is "g" and 'ㄹ'
+/// is "r", while it is "k" and "l" at the end of a syllable. 'ㄹ' followed by
+/// 'ㄹ' is "ll". The sound changes across syllables described in [`pronounce`]
+/// are written as they are heard, like "신라" as "silla", "같이" as "gachi"
+/// and "잡혀" as "japyeo", except for tensification, which the Revised
+/// Romanization doesn't write, so "국밥" is "gukbap".
+///
+/// In nouns, the Revised Romanization doesn't write the aspiration of 'ㄱ',
+/// 'ㄷ' and 'ㅂ' before 'ㅎ' either, as in "묵호"(Mukho) and
+/// "집현전"(Jiphyeonjeon). Nouns can't be told from other words, so this is
+/// not handled, and they are written as heard, "muko" and "jipyeonjeon".
+/// See [`romanize_with`] for other systems.
+///
+/// # Example
+/// ```
+/// use sejong::romanize;
+/// assert_eq!(romanize("안녕"), "annyeong");
+/// assert_eq!(romanize("한국어"), "hangugeo");
+/// assert_eq!(romanize("국밥"), "gukbap");
+/// assert_eq!(romanize("종로"), "jongno");
+/// assert_eq!(romanize("빨리, 서울!"), "ppalli, seoul!");
+/// ```
+///
+/// [`pronounce`]: crate::pronounce
+pub fn romanize(hangul: &str) -> String {
+    romanize_with(hangul, Romanization::Revised)
+}
+
+/// Romanizes Hangul in the given [`Romanization`]. Characters other than
+/// modern Hangul syllables are kept as they are, and end a word.
+///
+/// # Example
+/// ```
+/// use sejong::{romanize_with, Romanization};
+/// assert_eq!(romanize_with("한글", Romanization::McCuneReischauer), "han'gŭl");
+/// assert_eq!(romanize_with("김치", Romanization::McCuneReischauer), "kimch'i");
+/// assert_eq!(romanize_with("한글", Romanization::Yale), "hankul");
+/// assert_eq!(romanize_with("닭이", Romanization::Yale), "talk.i");
+/// ```
+pub fn romanize_with(hangul: &str, romanization: Romanization) -> String {
+    match romanization {
+        Romanization::Revised => REVISED.romanize(hangul),
+        Romanization::McCuneReischauer => MCCUNE_REISCHAUER.romanize(hangul),
+        Romanization::Yale => to_yale(hangul),
+    }
+}
+
+/// Reads Yale romanization back into Hangul. This is the inverse of
+/// [`romanize_with`] in [`Romanization::Yale`]. A period between letters
+/// separates syllables. Characters other than lowercase ASCII letters are
+/// kept as they are. Returns `None` when a word isn't Yale for modern Hangul.
+///
+/// # Example
+/// ```
+/// use sejong::from_yale;
+/// assert_eq!(from_yale("hankul"), Some("한글".to_string()));
+/// assert_eq!(from_yale("talk.i, talki."), Some("닭이, 달기.".to_string()));
+/// assert_eq!(from_yale("qa"), None);
+/// ```
+pub fn from_yale(latin: &str) -> Option<String> {
+    let chars: Vec<char> = latin.chars().collect();
+    let mut result = String::with_capacity(latin.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_lowercase() {
+            result.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len()
+            && (chars[i].is_ascii_lowercase()
+                || (chars[i] == '.' && chars.get(i + 1).is_some_and(char::is_ascii_lowercase)))
+        {
+            i += 1;
+        }
+        for part in chars[start..i].split(|c| *c == '.') {
+            let part: String = part.iter().collect();
+            result.extend(read_yale(&part)?);
+        }
+    }
+    Some(result)
+}
+
+impl Scheme {
+    fn romanize(&self, hangul: &str) -> String {
+        let mut result = String::with_capacity(hangul.len());
+        // The syllable before in the same word, `Some(None)` when it has no
+        // final consonant.
+        let mut before: Option<Option<FinalConsonant>> = None;
+        for c in hangul.chars() {
+            let (ic, mv, fc) = match decompose(c) {
+                Some(jamo) => jamo,
+                None => {
+                    if let Some(Some(fc)) = before.take() {
+                        result.push_str(self.finals[fc as usize - 1]);
+                    }
+                    result.push(c);
+                    continue;
+                }
+            };
+            match before.take() {
+                None => result.push_str(self.initial(ic, false)),
+                Some(None) => result.push_str(self.initial(ic, true)),
+                Some(Some(before)) => self.push_boundary(&mut result, before, ic, mv),
+            }
+            result.push_str(self.vowels[mv as usize]);
+            before = Some(fc);
+        }
+        if let Some(Some(fc)) = before {
+            result.push_str(self.finals[fc as usize - 1]);
+        }
+        result
+    }
+
+    fn initial(&self, ic: InitialConsonant, voiced: bool) -> &'static str {
+        match voiced {
+            true => self.voiced_initials[ic as usize],
+            false => self.initials[ic as usize],
+        }
+    }
+
+    // Romanizes a final consonant and the initial consonant after it.
+    fn push_boundary(
+        &self,
+        result: &mut String,
+        fc: FinalConsonant,
+        ic: InitialConsonant,
+        mv: MedialVowel,
+    ) {
+        let (fc, ic) = pronounce_boundary(fc, ic, mv, false);
+        if let Some(fc) = fc {
+            result.push_str(self.finals[fc as usize - 1]);
+        }
+        match (fc, ic) {
+            (Some(FinalConsonant::L), InitialConsonant::R) => result.push('l'),
+            (Some(FinalConsonant::N), InitialConsonant::G) if self.mccune_reischauer => {
+                result.push('\'');
+                result.push_str(self.initial(ic, true));
+            }
+            (None, _) => result.push_str(self.initial(ic, true)),
+            (Some(fc), _) => result.push_str(self.initial(ic, voiced(fc))),
+        }
+    }
+}
+
+// Whether a final consonant keeps the plain consonant after it voiced.
+fn voiced(fc: FinalConsonant) -> bool {
+    matches!(
+        fc,
+        FinalConsonant::N | FinalConsonant::L | FinalConsonant::M | FinalConsonant::NG
+    )
+}
+
+fn to_yale(hangul: &str) -> String {
+    let mut result = String::with_capacity(hangul.len());
+    let mut before = None;
+    for c in hangul.chars() {
+        match decompose(c) {
+            Some(jamo) => {
+                if before.is_some_and(|before| needs_period(before, c)) {
+                    result.push('.');
+                }
+                result.push_str(&yale(jamo));
+                before = Some(c);
+            }
+            None => {
+                result.push(c);
+                before = None;
+            }
+        }
+    }
+    result
+}
+
+fn yale((ic, mv, fc): (InitialConsonant, MedialVowel, Option<FinalConsonant>)) -> String {
+    let mut result = String::with_capacity(8);
+    result.push_str(YALE_INITIALS[ic as usize]);
+    result.push_str(YALE_VOWELS[mv as usize]);
+    if let Some(fc) = fc {
+        result.push_str(YALE_FINALS[fc as usize - 1]);
+    }
+    result
+}
+
+fn needs_period(before: char, after: char) -> bool {
+    let joined = yale(decompose(before).unwrap()) + &yale(decompose(after).unwrap());
+    read_yale(&joined) != Some(vec![before, after])
+}
+
+// Reads syllables written together, without periods. The consonants between
+// two vowels start the second syllable with the last of them when it can be an
+// initial consonant, and the rest is the final consonant of the first.
+fn read_yale(word: &str) -> Option<Vec<char>> {
+    let mut syllables = Vec::new();
+    let mut consonants: Vec<&str> = Vec::new();
+    let mut rest = word;
+    while !rest.is_empty() {
+        if let Some(vowel) = YALE_VOWEL_TOKENS.iter().find(|v| rest.starts_with(*v)) {
+            let ic = match consonants.last().and_then(|c| find(&YALE_INITIALS, c)) {
+                Some(index) => {
+                    consonants.pop();
+                    InitialConsonant::ALL[index]
+                }
+                None => InitialConsonant::NG,
+            };
+            add_final(&mut syllables, &mut consonants)?;
+            let mv = MedialVowel::ALL[find(&YALE_VOWELS, vowel)?];
+            syllables.push((ic, mv, None));
+            rest = &rest[vowel.len()..];
+        } else {
+            let consonant = YALE_CONSONANT_TOKENS
+                .iter()
+                .find(|c| rest.starts_with(*c))?;
+            consonants.push(consonant);
+            rest = &rest[consonant.len()..];
+        }
+    }
+    add_final(&mut syllables, &mut consonants)?;
+    syllables
+        .into_iter()
+        .map(|(ic, mv, fc)| compose(ic, mv, fc))
+        .collect()
+}
+
+type Decomposed = (InitialConsonant, MedialVowel, Option<FinalConsonant>);
+
+fn add_final(syllables: &mut [Decomposed], consonants: &mut Vec<&str>) -> Option<()> {
+    if consonants.is_empty() {
+        return Some(());
+    }
+    let last = syllables.last_mut()?;
+    let index = find(&YALE_FINALS, &consonants.concat())?;
+    last.2 = Some(FinalConsonant::ALL[index]);
+    consonants.clear();
+    Some(())
+}
+
+fn find(table: &[&str], spelling: &str) -> Option<usize> {
+    table.iter().position(|s| *s == spelling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::all_syllables;
+
+    #[test]
+    fn test_syllables() {
+        assert_eq!("ga", romanize("가"));
+        assert_eq!("kkot", romanize("꽃"));
+        assert_eq!("bak", romanize("밖"));
+        assert_eq!("waejwi", romanize("왜쥐"));
+        assert_eq!("uisa", romanize("의사"));
+        assert_eq!("busan", romanize("부산"));
+    }
+
+    #[test]
+    fn test_final_before_vowel() {
+        assert_eq!("dalgi", romanize("닭이"));
+        assert_eq!("joa", romanize("좋아"));
+        assert_eq!("ana", romanize("않아"));
+        assert_eq!("gangaji", romanize("강아지"));
+        assert_eq!("isseoyo", romanize("있어요"));
+        assert_eq!("mari", romanize("말이"));
+    }
+
+    #[test]
+    fn test_final_before_consonant() {
+        assert_eq!("dokdo", romanize("독도"));
+        assert_eq!("ikda", romanize("읽다"));
+        assert_eq!("eopda", romanize("없다"));
+        assert_eq!("ollyeo", romanize("올려"));
+        assert_eq!("daehanminguk", romanize("대한민국"));
+    }
+
+    #[test]
+    fn test_sound_changes() {
+        assert_eq!("silla", romanize("신라"));
+        assert_eq!("baengma", romanize("백마"));
+        assert_eq!("jongno", romanize("종로"));
+        assert_eq!("wangsimni", romanize("왕십리"));
+        assert_eq!("byeollae", romanize("별내"));
+        assert_eq!("daegwallyeong", romanize("대관령"));
+        assert_eq!("gachi", romanize("같이"));
+        assert_eq!("haedoji", romanize("해돋이"));
+        assert_eq!("joko", romanize("좋고"));
+        assert_eq!("nachi", romanize("낳지"));
+        assert_eq!("japyeo", romanize("잡혀"));
+        assert_eq!("guchida", romanize("굳히다"));
+        assert_eq!("ipak", romanize("입학"));
+    }
+
+    #[test]
+    fn test_unwritten_sound_changes() {
+        assert_eq!("apgujeong", romanize("압구정"));
+        assert_eq!("nakdonggang", romanize("낙동강"));
+        assert_eq!("gapseul", romanize("값을"));
+        assert_eq!("malge", romanize("맑게"));
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        assert_eq!("hak gyo", romanize("학 교"));
+        assert_eq!("ㄱa", romanize("ㄱa"));
+        assert_eq!("", romanize(""));
+    }
+
+    #[test]
+    fn test_mccune_reischauer() {
+        let mr = |hangul| romanize_with(hangul, Romanization::McCuneReischauer);
+        assert_eq!("pusan", mr("부산"));
+        assert_eq!("sŏul", mr("서울"));
+        assert_eq!("annyŏng", mr("안녕"));
+        assert_eq!("kukpap", mr("국밥"));
+        assert_eq!("kugi", mr("국이"));
+        assert_eq!("taehanmin'guk", mr("대한민국"));
+        assert_eq!("sijang", mr("시장"));
+        assert_eq!("ŭisa", mr("의사"));
+        assert_eq!("ch'ŏlsu", mr("철수"));
+        assert_eq!("tchigae", mr("찌개"));
+        assert_eq!("chongno", mr("종로"));
+        assert_eq!("silla", mr("신라"));
+        assert_eq!("tongnip", mr("독립"));
+        assert_eq!("paengma", mr("백마"));
+        assert_eq!("kungmin", mr("국민"));
+        assert_eq!("kach'i", mr("같이"));
+    }
+
+    #[test]
+    fn test_yale() {
+        let yale = |hangul| romanize_with(hangul, Romanization::Yale);
+        assert_eq!("kwukpap", yale("국밥"));
+        assert_eq!("kalwu", yale("가루"));
+        assert_eq!("kal.wu", yale("갈우"));
+        assert_eq!("ilkta", yale("읽다"));
+        assert_eq!("annyeng", yale("안녕"));
+        assert_eq!("cwungang", yale("중앙"));
+        assert_eq!("a.ya, 3", yale("아야, 3"));
+    }
+
+    #[test]
+    fn test_yale_round_trip() {
+        for c in all_syllables() {
+            let text = format!("{}{}가{}", c, c, c);
+            let latin = romanize_with(&text, Romanization::Yale);
+            assert_eq!(Some(text), from_yale(&latin), "{}", latin);
+        }
+    }
+
+    #[test]
+    fn test_from_yale() {
+        assert_eq!(Some("안녕. 가".to_string()), from_yale("annyeng. ka"));
+        assert_eq!(Some("달기".to_string()), from_yale("talki"));
+        assert_eq!(None, from_yale("kk"));
+        assert_eq!(None, from_yale("aklk"));
+        assert_eq!(Some("A".to_string()), from_yale("A"));
+    }
+}
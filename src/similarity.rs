@@ -0,0 +1,180 @@
+use crate::reverse::keystrokes;
+
+// Costs in halves of an edit, so that a typo on a nearby key can cost less
+// than a whole edit.
+const EDIT: u32 = 2;
+const NEARBY_KEY: u32 = 1;
+
+// QWERTY rows. Each row is shifted right from the one above it, so a key is
+// next to the key right above it and the one after that.
+const ROWS: [&[u8]; 3] = [b"qwertyuiop", b"asdfghjkl", b"zxcvbnm"];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Key {
+    Byte(u8),
+    Other(char),
+}
+
+/// The Levenshtein distance between two strings, counted in Jamo rather than
+/// in syllables. Hangul is taken apart into the keys that type it on a 2-set
+/// keyboard, so "갔" and "갓" are one edit apart, and so are "사과" and "사고".
+/// Characters other than Hangul count as one each.
+///
+/// # Example
+/// ```
+/// use sejong::levenshtein;
+/// assert_eq!(levenshtein("갔다", "갓다"), 1);
+/// assert_eq!(levenshtein("안녕", "아녕"), 1);
+/// assert_eq!(levenshtein("ㅏㄱ", "가"), 2);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    (distance(&keys(a), &keys(b), false, |_, _| EDIT) / EDIT) as usize
+}
+
+/// The Damerau-Levenshtein distance between two strings in Jamo, like
+/// [`levenshtein`], where swapping two Jamo next to each other is also one
+/// edit. Each Jamo is edited at most once.
+///
+/// # Example
+/// ```
+/// use sejong::damerau_levenshtein;
+/// assert_eq!(damerau_levenshtein("ㅏㄱ", "가"), 1);
+/// assert_eq!(damerau_levenshtein("갔다", "갓다"), 1);
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    (distance(&keys(a), &keys(b), true, |_, _| EDIT) / EDIT) as usize
+}
+
+/// How similar two strings are, from 0.0 to 1.0, for suggesting what a
+/// misspelled query meant. It is based on [`damerau_levenshtein`], but a Jamo
+/// typed in place of another on a nearby key, or the same key with or
+/// without Shift, is taken as a typo and costs half an edit. The distance is
+/// divided by the number of keys in the longer string.
+///
+/// # Example
+/// ```
+/// use sejong::similarity;
+/// // 'ㅌ' is next to 'ㄴ' on the keyboard, but 'ㄹ' is not.
+/// assert!(similarity("간식", "같식") > similarity("간식", "갈식"));
+/// assert_eq!(similarity("한글", "한글"), 1.0);
+/// ```
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (keys(a), keys(b));
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let cost = |a, b| match (a, b) {
+        (Key::Byte(a), Key::Byte(b)) if nearby(a, b) => NEARBY_KEY,
+        _ => EDIT,
+    };
+    1.0 - distance(&a, &b, true, cost) as f64 / (EDIT as usize * longest) as f64
+}
+
+fn keys(s: &str) -> Vec<Key> {
+    s.chars()
+        .flat_map(|c| match keystrokes(c) {
+            Some(bytes) => bytes.into_iter().map(|b| Key::Byte(b as u8)).collect(),
+            None => vec![Key::Other(c)],
+        })
+        .collect()
+}
+
+// The edit distance by dynamic programming, with the optimal string alignment
+// of adjacent transpositions when `transposition` is set.
+fn distance<F>(a: &[Key], b: &[Key], transposition: bool, substitution: F) -> u32
+where
+    F: Fn(Key, Key) -> u32,
+{
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i as u32 * EDIT;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j as u32 * EDIT;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substituted = match a[i - 1] == b[j - 1] {
+                true => 0,
+                false => substitution(a[i - 1], b[j - 1]),
+            };
+            let mut best = (table[i - 1][j] + EDIT)
+                .min(table[i][j - 1] + EDIT)
+                .min(table[i - 1][j - 1] + substituted);
+            if transposition && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(table[i - 2][j - 2] + EDIT);
+            }
+            table[i][j] = best;
+        }
+    }
+    table[a.len()][b.len()]
+}
+
+fn position(key: u8) -> Option<(usize, usize)> {
+    let key = key.to_ascii_lowercase();
+    ROWS.iter()
+        .enumerate()
+        .find_map(|(row, keys)| Some((row, keys.iter().position(|k| *k == key)?)))
+}
+
+// Whether two keys are next to each other, or the same key.
+fn nearby(a: u8, b: u8) -> bool {
+    let ((row_a, column_a), (row_b, column_b)) = match (position(a), position(b)) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return false,
+    };
+    if row_a == row_b {
+        return column_a.abs_diff(column_b) <= 1;
+    }
+    let ((upper, upper_column), (lower, lower_column)) = match row_a < row_b {
+        true => ((row_a, column_a), (row_b, column_b)),
+        false => ((row_b, column_b), (row_a, column_a)),
+    };
+    lower == upper + 1 && (upper_column == lower_column || upper_column == lower_column + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearby() {
+        assert!(nearby(b'g', b'h'));
+        assert!(nearby(b'g', b't'));
+        assert!(nearby(b'g', b'y'));
+        assert!(nearby(b'g', b'v'));
+        assert!(nearby(b'g', b'b'));
+        assert!(nearby(b'r', b'R'));
+        assert!(!nearby(b'g', b'r'));
+        assert!(!nearby(b'g', b'n'));
+        assert!(!nearby(b'q', b'p'));
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(0, levenshtein("", ""));
+        assert_eq!(0, levenshtein("안녕", "안녕"));
+        assert_eq!(1, levenshtein("사과", "사고"));
+        assert_eq!(1, levenshtein("갃", "각"));
+        assert_eq!(6, levenshtein("", "안녕"));
+        assert_eq!(2, levenshtein("가a", "가b!"));
+    }
+
+    #[test]
+    fn test_damerau_levenshtein() {
+        assert_eq!(1, damerau_levenshtein("갓다", "갇사"));
+        assert_eq!(2, levenshtein("갓다", "갇사"));
+        assert_eq!(1, damerau_levenshtein("ㅏㄱㅅ", "갓"));
+        assert_eq!(3, damerau_levenshtein("ca", "abc"));
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(1.0, similarity("", ""));
+        assert_eq!(0.0, similarity("가", "a"));
+        assert_eq!(1.0 - 1.0 / 6.0, similarity("갔", "갓"));
+        assert_eq!(1.0 - 2.0 / 6.0, similarity("간", "갈"));
+        assert!(similarity("사과", "사고") > similarity("사과", "사자"));
+    }
+}
@@ -0,0 +1,100 @@
+use super::syllable::calculate_syllable_u32;
+use super::*;
+use std::convert::TryFrom;
+
+/// Decomposes a modern Hangul syllable into its initial consonant, medial vowel
+/// and final consonant. Returns `None` for any other character, including
+/// Hangul Compatibility Jamo. Compound vowels and final consonants can be
+/// split further with [`MedialVowel::split`] and [`FinalConsonant::split`].
+///
+/// # Example
+/// ```
+/// use sejong::{decompose, FinalConsonant, InitialConsonant, MedialVowel};
+/// assert_eq!(
+///     decompose('읁'),
+///     Some((InitialConsonant::NG, MedialVowel::EU, Some(FinalConsonant::NJ)))
+/// );
+/// assert_eq!(decompose('가'), Some((InitialConsonant::G, MedialVowel::A, None)));
+/// assert_eq!(decompose('ㄱ'), None);
+/// ```
+pub fn decompose(c: char) -> Option<(InitialConsonant, MedialVowel, Option<FinalConsonant>)> {
+    match Syllable::try_from(c) {
+        Ok(Syllable::Medial(ic, mv)) => Some((ic, mv, None)),
+        Ok(Syllable::Final(ic, mv, fc)) => Some((ic, mv, Some(fc))),
+        _ => None,
+    }
+}
+
+/// Composes a modern Hangul syllable. This is the inverse of [`decompose`].
+/// Returns `None` when any of the Jamo is archaic, as there are no precomposed
+/// syllables of Old Hangul.
+///
+/// # Example
+/// ```
+/// use sejong::{compose, FinalConsonant, InitialConsonant, MedialVowel};
+/// assert_eq!(
+///     compose(InitialConsonant::NG, MedialVowel::EU, Some(FinalConsonant::NJ)),
+///     Some('읁')
+/// );
+/// assert_eq!(compose(InitialConsonant::G, MedialVowel::WA, None), Some('과'));
+/// assert_eq!(compose(InitialConsonant::PANSIOS, MedialVowel::A, None), None);
+/// ```
+pub fn compose(
+    initial_consonant: InitialConsonant,
+    medial_vowel: MedialVowel,
+    final_consonant: Option<FinalConsonant>,
+) -> Option<char> {
+    if initial_consonant.is_archaic()
+        || medial_vowel.is_archaic()
+        || final_consonant.is_some_and(FinalConsonant::is_archaic)
+    {
+        return None;
+    }
+    Some(calculate_syllable_u32(
+        initial_consonant as u32,
+        medial_vowel as u32,
+        final_consonant.map_or(0, |fc| fc as u32),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_every_syllable() {
+        for code in 0xac00..=0xd7a3 {
+            let c = std::char::from_u32(code).unwrap();
+            let (ic, mv, fc) = decompose(c).unwrap();
+            assert_eq!(compose(ic, mv, fc), Some(c));
+        }
+    }
+
+    #[test]
+    fn test_compose_archaic() {
+        let (ic, mv, fc) = (InitialConsonant::G, MedialVowel::A, FinalConsonant::G);
+        assert_eq!(compose(InitialConsonant::BSG, mv, Some(fc)), None);
+        assert_eq!(compose(ic, MedialVowel::ARAEA, Some(fc)), None);
+        assert_eq!(compose(ic, mv, Some(FinalConsonant::YESIEUNG)), None);
+    }
+
+    #[test]
+    fn test_decompose_non_syllable() {
+        assert_eq!(decompose('ㅏ'), None);
+        assert_eq!(decompose('a'), None);
+        assert_eq!(decompose('\u{d7a4}'), None);
+    }
+
+    #[test]
+    fn test_compatibility_jamo() {
+        assert_eq!(InitialConsonant::try_from('ㅉ'), Ok(InitialConsonant::JJ));
+        assert_eq!(InitialConsonant::try_from('ㄳ'), Err('ㄳ'));
+        assert_eq!(FinalConsonant::try_from('ㄳ'), Ok(FinalConsonant::GS));
+        assert_eq!(FinalConsonant::try_from('ㅉ'), Err('ㅉ'));
+        assert_eq!(MedialVowel::try_from('ㅢ'), Ok(MedialVowel::YI));
+        assert_eq!(MedialVowel::try_from('ㄱ'), Err('ㄱ'));
+        for fc in FinalConsonant::ALL.iter() {
+            assert_eq!(FinalConsonant::try_from(char::from(*fc)), Ok(*fc));
+        }
+    }
+}
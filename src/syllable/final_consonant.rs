@@ -1,89 +1,134 @@
-use super::{Byte, InitialConsonant};
+use super::InitialConsonant;
 use std::convert::{TryFrom, TryInto};
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+/// Final consonant(종성) of a Hangul syllable. The order follows the
+/// Hangul Syllables block in Unicode, where 0 stands for no final consonant.
+///
+/// The archaic consonants of Old Hangul(옛한글) come after the modern ones.
+/// There are no precomposed syllables with them, so
+/// [`compose`](crate::compose) returns `None` for them. More of them may be
+/// added, so the enum is non-exhaustive.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
 #[repr(u8)]
-pub(crate) enum FinalConsonant {
-    None,
-    G,  // ㄱ
-    KK, // ㄲ
-    GS, // ㄳ
-    N,  // ㄴ
-    NJ, // ㄵ
-    NH, // ㄶ
-    D,  // ㄷ
-    L,  // ㄹ
-    LG, // ㄺ
-    LM, // ㄻ
-    LB, // ㄼ
-    LS, // ㄽ
-    LT, // ㄾ
-    LP, // ㄿ
-    LH, // ㅀ
-    M,  // ㅁ
-    B,  // ㅂ
-    BS, // ㅄ
-    S,  // ㅅ
-    SS, // ㅆ
-    NG, // ㅇ
-    J,  // ㅈ
-    CH, // ㅊ
-    K,  // ㅋ
-    T,  // ㅌ
-    P,  // ㅍ
-    H,  // ㅎ
+pub enum FinalConsonant {
+    /// ㄱ
+    G = 1,
+    /// ㄲ
+    KK,
+    /// ㄳ
+    GS,
+    /// ㄴ
+    N,
+    /// ㄵ
+    NJ,
+    /// ㄶ
+    NH,
+    /// ㄷ
+    D,
+    /// ㄹ
+    L,
+    /// ㄺ
+    LG,
+    /// ㄻ
+    LM,
+    /// ㄼ
+    LB,
+    /// ㄽ
+    LS,
+    /// ㄾ
+    LT,
+    /// ㄿ
+    LP,
+    /// ㅀ
+    LH,
+    /// ㅁ
+    M,
+    /// ㅂ
+    B,
+    /// ㅄ
+    BS,
+    /// ㅅ
+    S,
+    /// ㅆ
+    SS,
+    /// ㅇ
+    NG,
+    /// ㅈ
+    J,
+    /// ㅊ
+    CH,
+    /// ㅋ
+    K,
+    /// ㅌ
+    T,
+    /// ㅍ
+    P,
+    /// ㅎ
+    H,
+    /// ㅿ
+    PANSIOS,
+    /// ㆁ
+    YESIEUNG,
+    /// ㆆ
+    YEORINHIEUH,
 }
 
-impl TryFrom<Byte> for FinalConsonant {
-    type Error = Byte;
-    fn try_from(b: Byte) -> Result<Self, Self::Error> {
-        match b {
-            Byte::G => Ok(Self::G),
-            Byte::KK => Ok(Self::KK),
-            Byte::N => Ok(Self::N),
-            Byte::D => Ok(Self::D),
-            Byte::R => Ok(Self::L),
-            Byte::M => Ok(Self::M),
-            Byte::B => Ok(Self::B),
-            Byte::S => Ok(Self::S),
-            Byte::SS => Ok(Self::SS),
-            Byte::NG => Ok(Self::NG),
-            Byte::J => Ok(Self::J),
-            Byte::CH => Ok(Self::CH),
-            Byte::K => Ok(Self::K),
-            Byte::T => Ok(Self::T),
-            Byte::P => Ok(Self::P),
-            Byte::H => Ok(Self::H),
-            _ => Err(b),
+impl TryFrom<InitialConsonant> for FinalConsonant {
+    type Error = InitialConsonant;
+    fn try_from(ic: InitialConsonant) -> Result<Self, Self::Error> {
+        match ic {
+            InitialConsonant::G => Ok(Self::G),
+            InitialConsonant::KK => Ok(Self::KK),
+            InitialConsonant::N => Ok(Self::N),
+            InitialConsonant::D => Ok(Self::D),
+            InitialConsonant::R => Ok(Self::L),
+            InitialConsonant::M => Ok(Self::M),
+            InitialConsonant::B => Ok(Self::B),
+            InitialConsonant::S => Ok(Self::S),
+            InitialConsonant::SS => Ok(Self::SS),
+            InitialConsonant::NG => Ok(Self::NG),
+            InitialConsonant::J => Ok(Self::J),
+            InitialConsonant::CH => Ok(Self::CH),
+            InitialConsonant::K => Ok(Self::K),
+            InitialConsonant::T => Ok(Self::T),
+            InitialConsonant::P => Ok(Self::P),
+            InitialConsonant::H => Ok(Self::H),
+            InitialConsonant::PANSIOS => Ok(Self::PANSIOS),
+            InitialConsonant::YESIEUNG => Ok(Self::YESIEUNG),
+            InitialConsonant::YEORINHIEUH => Ok(Self::YEORINHIEUH),
+            _ => Err(ic),
         }
     }
 }
 
-impl TryFrom<(Self, Byte)> for FinalConsonant {
-    type Error = (Self, Byte);
-    fn try_from(input: (Self, Byte)) -> Result<Self, Self::Error> {
+/// Combines two consonants into a compound final consonant, e.g. ㄹ and ㄱ into ㄺ.
+impl TryFrom<(Self, Self)> for FinalConsonant {
+    type Error = (Self, Self);
+    fn try_from(input: (Self, Self)) -> Result<Self, Self::Error> {
         match input.0 {
             Self::G => match input.1 {
-                Byte::S => Ok(Self::GS),
+                Self::S => Ok(Self::GS),
                 _ => Err(input),
             },
             Self::N => match input.1 {
-                Byte::J => Ok(Self::NJ),
-                Byte::H => Ok(Self::NH),
+                Self::J => Ok(Self::NJ),
+                Self::H => Ok(Self::NH),
                 _ => Err(input),
             },
             Self::L => match input.1 {
-                Byte::G => Ok(Self::LG),
-                Byte::M => Ok(Self::LM),
-                Byte::B => Ok(Self::LB),
-                Byte::S => Ok(Self::LS),
-                Byte::T => Ok(Self::LT),
-                Byte::P => Ok(Self::LP),
-                Byte::H => Ok(Self::LH),
+                Self::G => Ok(Self::LG),
+                Self::M => Ok(Self::LM),
+                Self::B => Ok(Self::LB),
+                Self::S => Ok(Self::LS),
+                Self::T => Ok(Self::LT),
+                Self::P => Ok(Self::LP),
+                Self::H => Ok(Self::LH),
                 _ => Err(input),
             },
             Self::B => match input.1 {
-                Byte::S => Ok(Self::BS),
+                Self::S => Ok(Self::BS),
                 _ => Err(input),
             },
             _ => Err(input),
@@ -91,12 +136,6 @@ impl TryFrom<(Self, Byte)> for FinalConsonant {
     }
 }
 
-impl Default for FinalConsonant {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl TryInto<(Self, InitialConsonant)> for FinalConsonant {
     type Error = Self;
     fn try_into(self) -> Result<(Self, InitialConsonant), Self> {
@@ -117,8 +156,136 @@ impl TryInto<(Self, InitialConsonant)> for FinalConsonant {
     }
 }
 
+impl From<FinalConsonant> for char {
+    fn from(fc: FinalConsonant) -> Self {
+        // Hangul Compability Jamo reference:
+        // https://en.wikipedia.org/wiki/Hangul_Compatibility_Jamo
+        match fc {
+            FinalConsonant::G => 'ㄱ',
+            FinalConsonant::KK => 'ㄲ',
+            FinalConsonant::GS => 'ㄳ',
+            FinalConsonant::N => 'ㄴ',
+            FinalConsonant::NJ => 'ㄵ',
+            FinalConsonant::NH => 'ㄶ',
+            FinalConsonant::D => 'ㄷ',
+            FinalConsonant::L => 'ㄹ',
+            FinalConsonant::LG => 'ㄺ',
+            FinalConsonant::LM => 'ㄻ',
+            FinalConsonant::LB => 'ㄼ',
+            FinalConsonant::LS => 'ㄽ',
+            FinalConsonant::LT => 'ㄾ',
+            FinalConsonant::LP => 'ㄿ',
+            FinalConsonant::LH => 'ㅀ',
+            FinalConsonant::M => 'ㅁ',
+            FinalConsonant::B => 'ㅂ',
+            FinalConsonant::BS => 'ㅄ',
+            FinalConsonant::S => 'ㅅ',
+            FinalConsonant::SS => 'ㅆ',
+            FinalConsonant::NG => 'ㅇ',
+            FinalConsonant::J => 'ㅈ',
+            FinalConsonant::CH => 'ㅊ',
+            FinalConsonant::K => 'ㅋ',
+            FinalConsonant::T => 'ㅌ',
+            FinalConsonant::P => 'ㅍ',
+            FinalConsonant::H => 'ㅎ',
+            FinalConsonant::PANSIOS => 'ㅿ',
+            FinalConsonant::YESIEUNG => 'ㆁ',
+            FinalConsonant::YEORINHIEUH => 'ㆆ',
+        }
+    }
+}
+
+/// Reads a Hangul Compatibility Jamo consonant of modern Hangul. ㄸ, ㅃ and ㅉ
+/// can't be final consonants and are returned as error.
+impl TryFrom<char> for FinalConsonant {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Self::ALL
+            .iter()
+            .find(|fc| char::from(**fc) == c)
+            .copied()
+            .ok_or(c)
+    }
+}
+
 impl FinalConsonant {
-    pub fn try_remove_second_half(self) -> Option<Self> {
+    // The modern consonants only, in the order of the Hangul Syllables block.
+    pub(crate) const ALL: [Self; 27] = [
+        Self::G,
+        Self::KK,
+        Self::GS,
+        Self::N,
+        Self::NJ,
+        Self::NH,
+        Self::D,
+        Self::L,
+        Self::LG,
+        Self::LM,
+        Self::LB,
+        Self::LS,
+        Self::LT,
+        Self::LP,
+        Self::LH,
+        Self::M,
+        Self::B,
+        Self::BS,
+        Self::S,
+        Self::SS,
+        Self::NG,
+        Self::J,
+        Self::CH,
+        Self::K,
+        Self::T,
+        Self::P,
+        Self::H,
+    ];
+
+    /// Returns the Hangul Jamo(conjoining) jongseong, e.g. U+11A8 for ㄱ.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::FinalConsonant;
+    /// assert_eq!(FinalConsonant::G.to_conjoining(), '\u{11a8}');
+    /// ```
+    pub fn to_conjoining(self) -> char {
+        match self {
+            Self::PANSIOS => '\u{11eb}',
+            Self::YESIEUNG => '\u{11f0}',
+            Self::YEORINHIEUH => '\u{11f9}',
+            _ => unsafe { std::char::from_u32_unchecked(self as u32 + 0x11a7) },
+        }
+    }
+
+    pub(crate) fn from_conjoining(c: char) -> Option<Self> {
+        match c as u32 {
+            0x11a8..=0x11c2 => Some(Self::ALL[(c as u32 - 0x11a8) as usize]),
+            _ => None,
+        }
+    }
+
+    // Whether this is an archaic consonant of Old Hangul.
+    pub(crate) fn is_archaic(self) -> bool {
+        self as u8 > Self::H as u8
+    }
+
+    /// Splits a compound final consonant into its two halves. A final consonant
+    /// that isn't compound is returned as the first half.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::FinalConsonant;
+    /// assert_eq!(FinalConsonant::LG.split(), (FinalConsonant::L, Some(FinalConsonant::G)));
+    /// assert_eq!(FinalConsonant::KK.split(), (FinalConsonant::KK, None));
+    /// ```
+    pub fn split(self) -> (Self, Option<Self>) {
+        let split_result: Result<(Self, InitialConsonant), Self> = self.try_into();
+        match split_result {
+            Ok((first, second)) => (first, Self::try_from(second).ok()),
+            Err(_) => (self, None),
+        }
+    }
+
+    pub(crate) fn try_remove_second_half(self) -> Option<Self> {
         match self {
             Self::GS => Some(Self::G),
             Self::NJ | Self::NH => Some(Self::N),
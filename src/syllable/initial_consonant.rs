@@ -1,28 +1,189 @@
 use super::{Byte, FinalConsonant};
 use std::convert::TryFrom;
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+/// Initial consonant(초성) of a Hangul syllable. The order follows the
+/// Hangul Syllables block in Unicode.
+///
+/// The archaic consonants and initial consonant clusters of Old Hangul(옛한글)
+/// come after the modern ones. There are no precomposed syllables with them,
+/// so [`compose`](crate::compose) returns `None` for them. More of them may be
+/// added, so the enum is non-exhaustive.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
 #[repr(u8)]
-pub(crate) enum InitialConsonant {
-    G,  // ㄱ
-    KK, // ㄲ
-    N,  // ㄴ
-    D,  // ㄷ
-    TT, // ㄸ
-    R,  // ㄹ
-    M,  // ㅁ
-    B,  // ㅂ
-    PP, // ㅃ
-    S,  // ㅅ
-    SS, // ㅆ
-    NG, // ㅇ
-    J,  // ㅈ
-    JJ, // ㅉ
-    CH, // ㅊ
-    K,  // ㅋ
-    T,  // ㅌ
-    P,  // ㅍ
-    H,  // ㅎ
+pub enum InitialConsonant {
+    /// ㄱ
+    G,
+    /// ㄲ
+    KK,
+    /// ㄴ
+    N,
+    /// ㄷ
+    D,
+    /// ㄸ
+    TT,
+    /// ㄹ
+    R,
+    /// ㅁ
+    M,
+    /// ㅂ
+    B,
+    /// ㅃ
+    PP,
+    /// ㅅ
+    S,
+    /// ㅆ
+    SS,
+    /// ㅇ
+    NG,
+    /// ㅈ
+    J,
+    /// ㅉ
+    JJ,
+    /// ㅊ
+    CH,
+    /// ㅋ
+    K,
+    /// ㅌ
+    T,
+    /// ㅍ
+    P,
+    /// ㅎ
+    H,
+    /// ㅿ
+    PANSIOS,
+    /// ㆁ
+    YESIEUNG,
+    /// ㆆ
+    YEORINHIEUH,
+    /// ㅸ
+    KAPYEOUNPIEUP,
+    /// ㅲ
+    BG,
+    /// ㅳ
+    BD,
+    /// ㅄ
+    BS,
+    /// ㅴ
+    BSG,
+    /// ㅵ
+    BSD,
+    /// ㅶ
+    BJ,
+    /// ㅷ
+    BT,
+    /// ㅺ
+    SG,
+    /// ㅻ
+    SN,
+    /// ㅼ
+    SD,
+    /// ㅽ
+    SB,
+    /// ㅾ
+    SJ,
+}
+
+impl InitialConsonant {
+    // Initial consonant clusters as (first, second, cluster).
+    const CLUSTERS: [(Self, Self, Self); 13] = [
+        (Self::B, Self::G, Self::BG),
+        (Self::B, Self::D, Self::BD),
+        (Self::B, Self::S, Self::BS),
+        (Self::BS, Self::G, Self::BSG),
+        (Self::BS, Self::D, Self::BSD),
+        (Self::B, Self::J, Self::BJ),
+        (Self::B, Self::T, Self::BT),
+        (Self::B, Self::NG, Self::KAPYEOUNPIEUP),
+        (Self::S, Self::G, Self::SG),
+        (Self::S, Self::N, Self::SN),
+        (Self::S, Self::D, Self::SD),
+        (Self::S, Self::B, Self::SB),
+        (Self::S, Self::J, Self::SJ),
+    ];
+
+    // The modern consonants only, in the order of the Hangul Syllables block.
+    pub(crate) const ALL: [Self; 19] = [
+        Self::G,
+        Self::KK,
+        Self::N,
+        Self::D,
+        Self::TT,
+        Self::R,
+        Self::M,
+        Self::B,
+        Self::PP,
+        Self::S,
+        Self::SS,
+        Self::NG,
+        Self::J,
+        Self::JJ,
+        Self::CH,
+        Self::K,
+        Self::T,
+        Self::P,
+        Self::H,
+    ];
+}
+
+impl InitialConsonant {
+    /// Returns the Hangul Jamo(conjoining) choseong, e.g. U+1100 for ㄱ.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::InitialConsonant;
+    /// assert_eq!(InitialConsonant::G.to_conjoining(), '\u{1100}');
+    /// ```
+    pub fn to_conjoining(self) -> char {
+        match self {
+            Self::PANSIOS => '\u{1140}',
+            Self::YESIEUNG => '\u{114c}',
+            Self::YEORINHIEUH => '\u{1159}',
+            Self::KAPYEOUNPIEUP => '\u{112b}',
+            Self::BG => '\u{111e}',
+            Self::BD => '\u{1120}',
+            Self::BS => '\u{1121}',
+            Self::BSG => '\u{1122}',
+            Self::BSD => '\u{1123}',
+            Self::BJ => '\u{1127}',
+            Self::BT => '\u{1129}',
+            Self::SG => '\u{112d}',
+            Self::SN => '\u{112e}',
+            Self::SD => '\u{112f}',
+            Self::SB => '\u{1132}',
+            Self::SJ => '\u{1136}',
+            _ => unsafe { std::char::from_u32_unchecked(self as u32 + 0x1100) },
+        }
+    }
+
+    pub(crate) fn from_conjoining(c: char) -> Option<Self> {
+        match c as u32 {
+            0x1100..=0x1112 => Some(Self::ALL[(c as u32 - 0x1100) as usize]),
+            _ => None,
+        }
+    }
+
+    // Whether this is an archaic consonant or a cluster of Old Hangul.
+    pub(crate) fn is_archaic(self) -> bool {
+        self as u8 > Self::H as u8
+    }
+
+    // Joins a consonant typed after this one into an initial consonant
+    // cluster, like ㅂ and ㅅ into ㅄ.
+    pub(crate) fn try_cluster(self, added: Self) -> Option<Self> {
+        Self::CLUSTERS
+            .iter()
+            .find(|(first, second, _)| *first == self && *second == added)
+            .map(|(_, _, cluster)| *cluster)
+    }
+
+    pub(crate) fn try_remove_second_half(self) -> Option<Self> {
+        Self::CLUSTERS
+            .iter()
+            .find(|(_, _, cluster)| *cluster == self)
+            .map(|(first, _, _)| *first)
+    }
 }
 
 impl TryFrom<Byte> for InitialConsonant {
@@ -73,41 +234,85 @@ impl TryFrom<FinalConsonant> for InitialConsonant {
             FinalConsonant::T => Ok(Self::T),
             FinalConsonant::P => Ok(Self::P),
             FinalConsonant::H => Ok(Self::H),
+            FinalConsonant::PANSIOS => Ok(Self::PANSIOS),
+            FinalConsonant::YESIEUNG => Ok(Self::YESIEUNG),
+            FinalConsonant::YEORINHIEUH => Ok(Self::YEORINHIEUH),
             _ => Err(fc),
         }
     }
 }
 
-impl Into<char> for InitialConsonant {
-    fn into(self) -> char {
-        // Hangul Compability Jamo reference:
-        // https://en.wikipedia.org/wiki/Hangul_Compatibility_Jamo
-        match self {
-            Self::G => 'ㄱ',
-            Self::KK => 'ㄲ',
-            Self::N => 'ㄴ',
-            Self::D => 'ㄷ',
-            Self::TT => 'ㄸ',
-            Self::R => 'ㄹ',
-            Self::M => 'ㅁ',
-            Self::B => 'ㅂ',
-            Self::PP => 'ㅃ',
-            Self::S => 'ㅅ',
-            Self::SS => 'ㅆ',
-            Self::NG => 'ㅇ',
-            Self::J => 'ㅈ',
-            Self::JJ => 'ㅉ',
-            Self::CH => 'ㅊ',
-            Self::K => 'ㅋ',
-            Self::T => 'ㅌ',
-            Self::P => 'ㅍ',
-            Self::H => 'ㅎ',
+/// Doubles a plain consonant into its tense counterpart, e.g. ㄱ and ㄱ into ㄲ.
+impl TryFrom<(Self, Self)> for InitialConsonant {
+    type Error = (Self, Self);
+    fn try_from(input: (Self, Self)) -> Result<Self, Self::Error> {
+        if input.0 != input.1 {
+            return Err(input);
+        }
+        match input.0 {
+            Self::G => Ok(Self::KK),
+            Self::D => Ok(Self::TT),
+            Self::B => Ok(Self::PP),
+            Self::S => Ok(Self::SS),
+            Self::J => Ok(Self::JJ),
+            _ => Err(input),
         }
     }
 }
 
-impl Default for InitialConsonant {
-    fn default() -> Self {
-        Self::G
+/// Reads a Hangul Compatibility Jamo consonant of modern Hangul. Compound
+/// consonants like ㄳ can't be initial consonants and are returned as error.
+impl TryFrom<char> for InitialConsonant {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Self::ALL
+            .iter()
+            .find(|ic| char::from(**ic) == c)
+            .copied()
+            .ok_or(c)
+    }
+}
+
+impl From<InitialConsonant> for char {
+    fn from(ic: InitialConsonant) -> Self {
+        // Hangul Compability Jamo reference:
+        // https://en.wikipedia.org/wiki/Hangul_Compatibility_Jamo
+        match ic {
+            InitialConsonant::G => 'ㄱ',
+            InitialConsonant::KK => 'ㄲ',
+            InitialConsonant::N => 'ㄴ',
+            InitialConsonant::D => 'ㄷ',
+            InitialConsonant::TT => 'ㄸ',
+            InitialConsonant::R => 'ㄹ',
+            InitialConsonant::M => 'ㅁ',
+            InitialConsonant::B => 'ㅂ',
+            InitialConsonant::PP => 'ㅃ',
+            InitialConsonant::S => 'ㅅ',
+            InitialConsonant::SS => 'ㅆ',
+            InitialConsonant::NG => 'ㅇ',
+            InitialConsonant::J => 'ㅈ',
+            InitialConsonant::JJ => 'ㅉ',
+            InitialConsonant::CH => 'ㅊ',
+            InitialConsonant::K => 'ㅋ',
+            InitialConsonant::T => 'ㅌ',
+            InitialConsonant::P => 'ㅍ',
+            InitialConsonant::H => 'ㅎ',
+            InitialConsonant::PANSIOS => 'ㅿ',
+            InitialConsonant::YESIEUNG => 'ㆁ',
+            InitialConsonant::YEORINHIEUH => 'ㆆ',
+            InitialConsonant::KAPYEOUNPIEUP => 'ㅸ',
+            InitialConsonant::BG => 'ㅲ',
+            InitialConsonant::BD => 'ㅳ',
+            InitialConsonant::BS => 'ㅄ',
+            InitialConsonant::BSG => 'ㅴ',
+            InitialConsonant::BSD => 'ㅵ',
+            InitialConsonant::BJ => 'ㅶ',
+            InitialConsonant::BT => 'ㅷ',
+            InitialConsonant::SG => 'ㅺ',
+            InitialConsonant::SN => 'ㅻ',
+            InitialConsonant::SD => 'ㅼ',
+            InitialConsonant::SB => 'ㅽ',
+            InitialConsonant::SJ => 'ㅾ',
+        }
     }
 }
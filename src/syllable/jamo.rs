@@ -0,0 +1,33 @@
+use super::*;
+use std::convert::TryFrom;
+
+/// A Jamo typed by a key, as read by a [`Layout`](crate::Layout).
+///
+/// `Consonant` and `Vowel` are what 2-set layouts type: a consonant becomes an
+/// initial or a final consonant depending on the keys around it, and a final
+/// consonant moves to the next syllable when a vowel follows it.
+/// `Initial`, `Medial` and `Final` are what 3-set layouts type: their position
+/// in the syllable is decided by the key, so they never move.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Jamo {
+    /// A consonant whose position is decided by composition.
+    Consonant(InitialConsonant),
+    /// A vowel that can take the final consonant of the previous syllable.
+    Vowel(MedialVowel),
+    /// A consonant that can only start a syllable.
+    Initial(InitialConsonant),
+    /// A vowel that never takes the final consonant of the previous syllable.
+    Medial(MedialVowel),
+    /// A consonant that can only end a syllable.
+    Final(FinalConsonant),
+}
+
+impl From<Byte> for Jamo {
+    fn from(b: Byte) -> Self {
+        if b.is_consonant() {
+            Self::Consonant(InitialConsonant::try_from(b).unwrap())
+        } else {
+            Self::Vowel(MedialVowel::try_from(b).unwrap())
+        }
+    }
+}
@@ -1,30 +1,64 @@
 use super::Byte;
 use std::convert::TryFrom;
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+/// Medial vowel(중성) of a Hangul syllable. The order follows the
+/// Hangul Syllables block in Unicode.
+///
+/// The archaic vowels of Old Hangul(옛한글) come after the modern ones. There
+/// are no precomposed syllables with them, so [`compose`](crate::compose)
+/// returns `None` for them. More of them may be added, so the enum is
+/// non-exhaustive.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
 #[repr(u8)]
-pub(crate) enum MedialVowel {
-    A,   // ㅏ
-    AE,  // ㅐ
-    YA,  // ㅑ
-    YAE, // ㅒ
-    EO,  // ㅓ
-    E,   // ㅔ
-    YEO, // ㅕ
-    YE,  // ㅖ
-    O,   // ㅗ
-    WA,  // ㅘ
-    WAE, // ㅙ
-    OE,  // ㅚ
-    YO,  // ㅛ
-    U,   // ㅜ
-    WO,  // ㅝ
-    WE,  // ㅞ
-    WI,  // ㅟ
-    YU,  // ㅠ
-    EU,  // ㅡ
-    YI,  // ㅢ
-    I,   // ㅣ
+pub enum MedialVowel {
+    /// ㅏ
+    A,
+    /// ㅐ
+    AE,
+    /// ㅑ
+    YA,
+    /// ㅒ
+    YAE,
+    /// ㅓ
+    EO,
+    /// ㅔ
+    E,
+    /// ㅕ
+    YEO,
+    /// ㅖ
+    YE,
+    /// ㅗ
+    O,
+    /// ㅘ
+    WA,
+    /// ㅙ
+    WAE,
+    /// ㅚ
+    OE,
+    /// ㅛ
+    YO,
+    /// ㅜ
+    U,
+    /// ㅝ
+    WO,
+    /// ㅞ
+    WE,
+    /// ㅟ
+    WI,
+    /// ㅠ
+    YU,
+    /// ㅡ
+    EU,
+    /// ㅢ
+    YI,
+    /// ㅣ
+    I,
+    /// ㆍ
+    ARAEA,
+    /// ㆎ
+    ARAEAE,
 }
 
 impl TryFrom<Byte> for MedialVowel {
@@ -50,39 +84,39 @@ impl TryFrom<Byte> for MedialVowel {
     }
 }
 
-impl Default for MedialVowel {
-    fn default() -> Self {
-        Self::A
-    }
-}
-
-impl Into<char> for MedialVowel {
-    fn into(self) -> char {
-        unsafe {
-            let x = std::char::from_u32_unchecked(self as u32 + 0x314f);
-            x
+impl From<MedialVowel> for char {
+    fn from(mv: MedialVowel) -> Self {
+        match mv {
+            MedialVowel::ARAEA => 'ㆍ',
+            MedialVowel::ARAEAE => 'ㆎ',
+            _ => unsafe { std::char::from_u32_unchecked(mv as u32 + 0x314f) },
         }
     }
 }
 
-impl TryFrom<(Self, Byte)> for MedialVowel {
-    type Error = (Self, Byte);
-    fn try_from(input: (Self, Byte)) -> Result<Self, Self::Error> {
+/// Combines two vowels into a compound vowel, e.g. ㅗ and ㅏ into ㅘ.
+impl TryFrom<(Self, Self)> for MedialVowel {
+    type Error = (Self, Self);
+    fn try_from(input: (Self, Self)) -> Result<Self, Self::Error> {
         match input.0 {
             Self::O => match input.1 {
-                Byte::A => Ok(Self::WA),
-                Byte::AE => Ok(Self::WAE),
-                Byte::I => Ok(Self::OE),
+                Self::A => Ok(Self::WA),
+                Self::AE => Ok(Self::WAE),
+                Self::I => Ok(Self::OE),
                 _ => Err(input),
             },
             Self::U => match input.1 {
-                Byte::EO => Ok(Self::WO),
-                Byte::E => Ok(Self::WE),
-                Byte::I => Ok(Self::WI),
+                Self::EO => Ok(Self::WO),
+                Self::E => Ok(Self::WE),
+                Self::I => Ok(Self::WI),
                 _ => Err(input),
             },
             Self::EU => match input.1 {
-                Byte::I => Ok(Self::YI),
+                Self::I => Ok(Self::YI),
+                _ => Err(input),
+            },
+            Self::ARAEA => match input.1 {
+                Self::I => Ok(Self::ARAEAE),
                 _ => Err(input),
             },
             _ => Err(input),
@@ -90,12 +124,99 @@ impl TryFrom<(Self, Byte)> for MedialVowel {
     }
 }
 
+/// Reads a Hangul Compatibility Jamo vowel of modern Hangul.
+impl TryFrom<char> for MedialVowel {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c as u32 {
+            0x314f..=0x3163 => Ok(Self::ALL[(c as u32 - 0x314f) as usize]),
+            _ => Err(c),
+        }
+    }
+}
+
 impl MedialVowel {
-    pub fn try_remove_second_half(self) -> Option<Self> {
+    // The modern vowels only, in the order of the Hangul Syllables block.
+    pub(crate) const ALL: [Self; 21] = [
+        Self::A,
+        Self::AE,
+        Self::YA,
+        Self::YAE,
+        Self::EO,
+        Self::E,
+        Self::YEO,
+        Self::YE,
+        Self::O,
+        Self::WA,
+        Self::WAE,
+        Self::OE,
+        Self::YO,
+        Self::U,
+        Self::WO,
+        Self::WE,
+        Self::WI,
+        Self::YU,
+        Self::EU,
+        Self::YI,
+        Self::I,
+    ];
+
+    /// Returns the Hangul Jamo(conjoining) jungseong, e.g. U+1161 for ㅏ.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::MedialVowel;
+    /// assert_eq!(MedialVowel::A.to_conjoining(), '\u{1161}');
+    /// ```
+    pub fn to_conjoining(self) -> char {
+        match self {
+            Self::ARAEA => '\u{119e}',
+            Self::ARAEAE => '\u{11a1}',
+            _ => unsafe { std::char::from_u32_unchecked(self as u32 + 0x1161) },
+        }
+    }
+
+    pub(crate) fn from_conjoining(c: char) -> Option<Self> {
+        match c as u32 {
+            0x1161..=0x1175 => Some(Self::ALL[(c as u32 - 0x1161) as usize]),
+            _ => None,
+        }
+    }
+
+    // Whether this is an archaic vowel of Old Hangul.
+    pub(crate) fn is_archaic(self) -> bool {
+        self as u8 > Self::I as u8
+    }
+
+    /// Splits a compound vowel into its two halves. A vowel that isn't compound
+    /// is returned as the first half.
+    ///
+    /// # Example
+    /// ```
+    /// use sejong::MedialVowel;
+    /// assert_eq!(MedialVowel::WA.split(), (MedialVowel::O, Some(MedialVowel::A)));
+    /// assert_eq!(MedialVowel::YA.split(), (MedialVowel::YA, None));
+    /// ```
+    pub fn split(self) -> (Self, Option<Self>) {
+        match self {
+            Self::WA => (Self::O, Some(Self::A)),
+            Self::WAE => (Self::O, Some(Self::AE)),
+            Self::OE => (Self::O, Some(Self::I)),
+            Self::WO => (Self::U, Some(Self::EO)),
+            Self::WE => (Self::U, Some(Self::E)),
+            Self::WI => (Self::U, Some(Self::I)),
+            Self::YI => (Self::EU, Some(Self::I)),
+            Self::ARAEAE => (Self::ARAEA, Some(Self::I)),
+            _ => (self, None),
+        }
+    }
+
+    pub(crate) fn try_remove_second_half(self) -> Option<Self> {
         match self {
             Self::WA | Self::WAE | Self::OE => Some(Self::O),
             Self::WO | Self::WE | Self::WI => Some(Self::U),
             Self::YI => Some(Self::EU),
+            Self::ARAEAE => Some(Self::ARAEA),
             _ => None,
         }
     }
@@ -1,11 +1,16 @@
+mod compose;
 mod final_consonant;
 mod initial_consonant;
+mod jamo;
 mod medial_vowel;
+#[allow(clippy::module_inception)]
 mod syllable;
 
 use super::byte::*;
-use final_consonant::*;
-use initial_consonant::*;
-use medial_vowel::*;
 
-pub(crate) use syllable::Syllable;
+pub use compose::{compose, decompose};
+pub use final_consonant::FinalConsonant;
+pub use initial_consonant::InitialConsonant;
+pub use jamo::Jamo;
+pub use medial_vowel::MedialVowel;
+pub(crate) use syllable::{Rules, Syllable};
@@ -1,4 +1,5 @@
 use super::*;
+use crate::conjoining::{CHOSEONG_FILLER, JUNGSEONG_FILLER};
 use std::convert::{TryFrom, TryInto};
 
 #[derive(Clone, Copy, Debug)]
@@ -7,50 +8,89 @@ pub(crate) enum Syllable {
     Medial(InitialConsonant, MedialVowel),
     Final(InitialConsonant, MedialVowel, FinalConsonant),
     VowelOnly(MedialVowel),
+    FinalOnly(FinalConsonant),
+    // Any other character, kept as it is. Nothing composes with it.
+    Literal(char),
+}
+
+/// Composition rules that are not always wanted. All of them are off by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Rules {
+    /// 2-set Jamo that don't make a syllable combine into compound Jamo,
+    /// like ㄱ and ㅅ into ㄳ or ㅗ and ㅏ into ㅘ.
+    pub compound_jamo: bool,
+    /// A 2-set consonant typed twice becomes its tense consonant, like ㄱ and
+    /// ㄱ into ㄲ.
+    pub double_tap: bool,
+    /// Moachigi: a vowel on its own takes the consonant typed after it as its
+    /// initial consonant, like ㅏ and ㄱ into 가.
+    pub moachigi: bool,
+    /// 2-set consonants typed one after another at the start of a syllable
+    /// make an initial consonant cluster of Old Hangul, like ㅂ and ㅅ into ㅄ.
+    pub initial_clusters: bool,
 }
 
 impl Syllable {
-    pub fn put(&mut self, byte: Byte) -> Option<Byte> {
-        if let Some(new) = self.update(byte) {
+    pub fn put(&mut self, jamo: Jamo, rules: Rules) -> Option<Jamo> {
+        if let Some(new) = self.update(jamo, rules) {
             *self = new;
             None
         } else {
-            Some(byte)
+            Some(jamo)
         }
     }
-    pub fn try_split_with_vowel(&mut self, byte: Byte) -> Result<Self, Byte> {
-        if !byte.is_vowel() {
-            return Err(byte);
+    pub fn try_split_with_vowel(&mut self, jamo: Jamo) -> Result<Self, Jamo> {
+        if !matches!(jamo, Jamo::Vowel(_)) {
+            return Err(jamo);
         }
-        if let Self::Final(ic, mv, fc) = self {
-            if let Ok(new_ic) = InitialConsonant::try_from(*fc) {
-                let mut splitted = Self::Initial(new_ic);
-                if let Some(byte) = splitted.put(byte) {
-                    return Err(byte);
+        match self {
+            Self::Final(ic, mv, fc) => {
+                if let Ok(new_ic) = InitialConsonant::try_from(*fc) {
+                    let mut splitted = Self::Initial(new_ic);
+                    if let Some(jamo) = splitted.put(jamo, Rules::default()) {
+                        return Err(jamo);
+                    }
+                    *self = Self::Medial(*ic, *mv);
+                    Ok(splitted)
+                } else {
+                    let split_result: Result<(FinalConsonant, InitialConsonant), FinalConsonant> =
+                        (*fc).try_into();
+                    if let Ok(consonants) = split_result {
+                        let mut splitted = Self::Initial(consonants.1);
+                        if let Some(jamo) = splitted.put(jamo, Rules::default()) {
+                            return Err(jamo);
+                        }
+                        *self = Self::Final(*ic, *mv, consonants.0);
+                        Ok(splitted)
+                    } else {
+                        Err(jamo)
+                    }
                 }
-                *self = Self::Medial(*ic, *mv);
-                Ok(splitted)
-            } else {
+            }
+            // A compound consonant on its own, like ㄳ, gives its second half.
+            Self::FinalOnly(fc) => {
                 let split_result: Result<(FinalConsonant, InitialConsonant), FinalConsonant> =
                     (*fc).try_into();
                 if let Ok(consonants) = split_result {
                     let mut splitted = Self::Initial(consonants.1);
-                    if let Some(byte) = splitted.put(byte) {
-                        return Err(byte);
+                    if let Some(jamo) = splitted.put(jamo, Rules::default()) {
+                        return Err(jamo);
                     }
-                    *self = Self::Final(*ic, *mv, consonants.0);
+                    *self = InitialConsonant::try_from(consonants.0)
+                        .map(Self::Initial)
+                        .unwrap_or(Self::FinalOnly(consonants.0));
                     Ok(splitted)
                 } else {
-                    Err(byte)
+                    Err(jamo)
                 }
             }
-        } else {
-            Err(byte)
+            _ => Err(jamo),
         }
     }
 
     pub fn remove_last(&mut self) -> Option<()> {
         match match self {
+            Self::Initial(ic) => ic.try_remove_second_half().map(Self::Initial),
             Self::Medial(ic, mv) => match mv.try_remove_second_half() {
                 Some(new_mv) => Some(Self::Medial(*ic, new_mv)),
                 None => Some(Self::Initial(*ic)),
@@ -59,6 +99,8 @@ impl Syllable {
                 Some(new_fc) => Some(Self::Final(*ic, *mv, new_fc)),
                 None => Some(Self::Medial(*ic, *mv)),
             },
+            Self::VowelOnly(mv) => mv.try_remove_second_half().map(Self::VowelOnly),
+            Self::FinalOnly(fc) => fc.try_remove_second_half().map(Self::FinalOnly),
             _ => None,
         } {
             Some(new) => {
@@ -69,29 +111,168 @@ impl Syllable {
         }
     }
 
-    fn update(&self, byte: Byte) -> Option<Self> {
+    /// The syllable as Hangul Jamo(conjoining), the way NFD decomposes it.
+    /// A syllable without an initial consonant or a vowel has the filler
+    /// U+115F or U+1160 in its place, so that the Jamo of the next syllable
+    /// don't join it. A consonant on its own is a choseong when it can start a
+    /// syllable and a jongseong otherwise.
+    pub fn conjoining(self) -> impl Iterator<Item = char> {
+        let jamo = match self {
+            Self::Initial(ic) => [Some(ic.to_conjoining()), Some(JUNGSEONG_FILLER), None],
+            Self::Medial(ic, mv) => [Some(ic.to_conjoining()), Some(mv.to_conjoining()), None],
+            Self::Final(ic, mv, fc) => [
+                Some(ic.to_conjoining()),
+                Some(mv.to_conjoining()),
+                Some(fc.to_conjoining()),
+            ],
+            Self::VowelOnly(mv) => [Some(CHOSEONG_FILLER), Some(mv.to_conjoining()), None],
+            Self::FinalOnly(fc) => [
+                Some(CHOSEONG_FILLER),
+                Some(JUNGSEONG_FILLER),
+                Some(fc.to_conjoining()),
+            ],
+            Self::Literal(c) => [Some(c), None, None],
+        };
+        IntoIterator::into_iter(jamo).flatten()
+    }
+
+    /// The syllable the way NFC composes it: its precomposed character, or its
+    /// Hangul Jamo(conjoining) when it has archaic Jamo.
+    pub fn precomposed(self) -> impl Iterator<Item = char> {
+        let (precomposed, conjoining) = match char::try_from(self) {
+            Ok(c) => (Some(c), None),
+            Err(syl) => (None, Some(syl.conjoining())),
+        };
+        precomposed
+            .into_iter()
+            .chain(conjoining.into_iter().flatten())
+    }
+
+    /// Whether the syllable has an archaic Jamo of Old Hangul.
+    pub fn is_archaic(self) -> bool {
         match self {
-            Self::Initial(ic) => Self::handle_initial(ic, byte),
-            Self::Medial(ic, mv) => Self::handle_medial(ic, mv, byte),
-            Self::Final(ic, mv, fc) => Self::handle_final(ic, mv, fc, byte),
-            Self::VowelOnly(_) => None,
+            Self::Initial(ic) => ic.is_archaic(),
+            Self::Medial(ic, mv) => ic.is_archaic() || mv.is_archaic(),
+            Self::Final(ic, mv, fc) => ic.is_archaic() || mv.is_archaic() || fc.is_archaic(),
+            Self::VowelOnly(mv) => mv.is_archaic(),
+            Self::FinalOnly(fc) => fc.is_archaic(),
+            Self::Literal(_) => false,
         }
     }
 
-    fn handle_initial(ic: &InitialConsonant, byte: Byte) -> Option<Self> {
-        match MedialVowel::try_from(byte) {
-            Ok(mv) => Some(Self::Medial(*ic, mv)),
-            Err(_) => None,
+    /// The 2-set keys that type this syllable from an empty buffer. There are
+    /// none for a literal, and none for the archaic Jamo of Old Hangul.
+    pub fn bytes(&self) -> Vec<Byte> {
+        let mut bytes = Vec::with_capacity(5);
+        match self {
+            Self::Initial(ic) => bytes.extend(Byte::try_from(*ic).ok()),
+            Self::Medial(ic, mv) => {
+                bytes.extend(Byte::try_from(*ic).ok());
+                Self::push_vowel_bytes(mv, &mut bytes);
+            }
+            Self::Final(ic, mv, fc) => {
+                bytes.extend(Byte::try_from(*ic).ok());
+                Self::push_vowel_bytes(mv, &mut bytes);
+                Self::push_final_bytes(fc, &mut bytes);
+            }
+            Self::VowelOnly(mv) => Self::push_vowel_bytes(mv, &mut bytes),
+            Self::FinalOnly(fc) => Self::push_final_bytes(fc, &mut bytes),
+            Self::Literal(_) => {}
         }
+        bytes
     }
 
-    fn handle_medial(ic: &InitialConsonant, mv: &MedialVowel, byte: Byte) -> Option<Self> {
-        if let Ok(added) = MedialVowel::try_from((*mv, byte)) {
-            return Some(Self::Medial(*ic, added));
+    fn push_vowel_bytes(mv: &MedialVowel, bytes: &mut Vec<Byte>) {
+        let (first, second) = mv.split();
+        bytes.extend(Byte::try_from(first).ok());
+        if let Some(second) = second {
+            bytes.push(Byte::try_from(second).unwrap());
         }
-        match FinalConsonant::try_from(byte) {
-            Ok(fc) => Some(Self::Final(*ic, *mv, fc)),
-            Err(_) => None,
+    }
+
+    fn push_final_bytes(fc: &FinalConsonant, bytes: &mut Vec<Byte>) {
+        let (first, second) = fc.split();
+        bytes.extend(Byte::try_from(first).ok());
+        if let Some(second) = second {
+            bytes.push(Byte::try_from(second).unwrap());
+        }
+    }
+
+    fn update(&self, jamo: Jamo, rules: Rules) -> Option<Self> {
+        match self {
+            Self::Initial(ic) => Self::handle_initial(ic, jamo, rules),
+            Self::Medial(ic, mv) => Self::handle_medial(ic, mv, jamo),
+            Self::Final(ic, mv, fc) => Self::handle_final(ic, mv, fc, jamo, rules),
+            Self::VowelOnly(mv) => Self::handle_vowel_only(mv, jamo, rules),
+            Self::Literal(_) => None,
+            Self::FinalOnly(fc) => Self::handle_final_only(fc, jamo),
+        }
+    }
+
+    fn handle_initial(ic: &InitialConsonant, jamo: Jamo, rules: Rules) -> Option<Self> {
+        match jamo {
+            Jamo::Vowel(mv) | Jamo::Medial(mv) => Some(Self::Medial(*ic, mv)),
+            Jamo::Initial(added) => match InitialConsonant::try_from((*ic, added)) {
+                Ok(doubled) => Some(Self::Initial(doubled)),
+                Err(_) => None,
+            },
+            Jamo::Consonant(added) => {
+                if rules.double_tap {
+                    if let Ok(doubled) = InitialConsonant::try_from((*ic, added)) {
+                        return Some(Self::Initial(doubled));
+                    }
+                }
+                if rules.initial_clusters {
+                    if let Some(cluster) = ic.try_cluster(added) {
+                        return Some(Self::Initial(cluster));
+                    }
+                }
+                if !rules.compound_jamo {
+                    return None;
+                }
+                let fc = FinalConsonant::try_from(*ic).ok()?;
+                Self::add_to_final(&fc, jamo).map(Self::FinalOnly)
+            }
+            _ => None,
+        }
+    }
+
+    fn handle_vowel_only(mv: &MedialVowel, jamo: Jamo, rules: Rules) -> Option<Self> {
+        match jamo {
+            Jamo::Vowel(added) | Jamo::Medial(added) if rules.compound_jamo => {
+                MedialVowel::try_from((*mv, added))
+                    .ok()
+                    .map(Self::VowelOnly)
+            }
+            Jamo::Consonant(ic) | Jamo::Initial(ic) if rules.moachigi => {
+                Some(Self::Medial(ic, *mv))
+            }
+            _ => None,
+        }
+    }
+
+    fn handle_final_only(fc: &FinalConsonant, jamo: Jamo) -> Option<Self> {
+        match jamo {
+            // Left by popping the second half of a compound consonant.
+            Jamo::Vowel(mv) => InitialConsonant::try_from(*fc)
+                .ok()
+                .map(|ic| Self::Medial(ic, mv)),
+            _ => Self::add_to_final(fc, jamo).map(Self::FinalOnly),
+        }
+    }
+
+    fn handle_medial(ic: &InitialConsonant, mv: &MedialVowel, jamo: Jamo) -> Option<Self> {
+        match jamo {
+            Jamo::Vowel(added) | Jamo::Medial(added) => match MedialVowel::try_from((*mv, added)) {
+                Ok(new) => Some(Self::Medial(*ic, new)),
+                Err(_) => None,
+            },
+            Jamo::Consonant(c) => match FinalConsonant::try_from(c) {
+                Ok(fc) => Some(Self::Final(*ic, *mv, fc)),
+                Err(_) => None,
+            },
+            Jamo::Final(fc) => Some(Self::Final(*ic, *mv, fc)),
+            Jamo::Initial(_) => None,
         }
     }
 
@@ -99,42 +280,100 @@ impl Syllable {
         ic: &InitialConsonant,
         mv: &MedialVowel,
         fc: &FinalConsonant,
-        byte: Byte,
+        jamo: Jamo,
+        rules: Rules,
     ) -> Option<Self> {
-        match FinalConsonant::try_from((*fc, byte)) {
-            Ok(new) => Some(Self::Final(*ic, *mv, new)),
-            Err(_) => None,
-        }
+        Self::add_to_final(fc, jamo)
+            .or_else(|| match rules.double_tap {
+                true => Self::double_final(fc, jamo),
+                false => None,
+            })
+            .map(|new| Self::Final(*ic, *mv, new))
+    }
+
+    // Only ㄱ and ㅅ have a tense counterpart that can be a final consonant.
+    fn double_final(fc: &FinalConsonant, jamo: Jamo) -> Option<FinalConsonant> {
+        let added = match jamo {
+            Jamo::Consonant(added) => added,
+            _ => return None,
+        };
+        let ic = InitialConsonant::try_from(*fc).ok()?;
+        let doubled = InitialConsonant::try_from((ic, added)).ok()?;
+        FinalConsonant::try_from(doubled).ok()
+    }
+
+    fn add_to_final(fc: &FinalConsonant, jamo: Jamo) -> Option<FinalConsonant> {
+        let added = match jamo {
+            Jamo::Consonant(c) => FinalConsonant::try_from(c).ok()?,
+            Jamo::Final(added) => added,
+            _ => return None,
+        };
+        FinalConsonant::try_from((*fc, added)).ok()
     }
 }
 
-impl Into<char> for Syllable {
-    fn into(self) -> char {
-        match self {
-            Self::Initial(ic) => ic.into(),
-            Self::Medial(ic, mv) => calculate_syllable_u32(ic as u32, mv as u32, 0),
-            Self::Final(ic, mv, fc) => calculate_syllable_u32(ic as u32, mv as u32, fc as u32),
-            Self::VowelOnly(v) => v.into(),
+/// A syllable with archaic Jamo has no precomposed character and is returned
+/// as error. A Jamo on its own always has its Hangul Compatibility Jamo.
+impl TryFrom<Syllable> for char {
+    type Error = Syllable;
+    fn try_from(syl: Syllable) -> Result<Self, Self::Error> {
+        Ok(match syl {
+            Syllable::Initial(ic) => ic.into(),
+            Syllable::Medial(..) | Syllable::Final(..) if syl.is_archaic() => return Err(syl),
+            Syllable::Medial(ic, mv) => calculate_syllable_u32(ic as u32, mv as u32, 0),
+            Syllable::Final(ic, mv, fc) => calculate_syllable_u32(ic as u32, mv as u32, fc as u32),
+            Syllable::VowelOnly(v) => v.into(),
+            Syllable::FinalOnly(fc) => fc.into(),
+            Syllable::Literal(c) => c,
+        })
+    }
+}
+
+/// The reverse of `char::try_from(Syllable)`, for modern Hangul. A Hangul Compatibility Jamo
+/// consonant becomes `Initial` when it can start a syllable and `FinalOnly`
+/// otherwise.
+impl TryFrom<char> for Syllable {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let code = c as u32;
+        if (HANGUL_SYLLABLE_FIRST..=HANGUL_SYLLABLE_LAST).contains(&code) {
+            let index = code - HANGUL_SYLLABLE_FIRST;
+            let ic = InitialConsonant::ALL[(index / 588) as usize];
+            let mv = MedialVowel::ALL[(index % 588 / 28) as usize];
+            return Ok(match index % 28 {
+                0 => Self::Medial(ic, mv),
+                fc => Self::Final(ic, mv, FinalConsonant::ALL[fc as usize - 1]),
+            });
+        }
+        if let Ok(mv) = MedialVowel::try_from(c) {
+            return Ok(Self::VowelOnly(mv));
+        }
+        if let Ok(ic) = InitialConsonant::try_from(c) {
+            return Ok(Self::Initial(ic));
         }
+        FinalConsonant::try_from(c).map(Self::FinalOnly)
     }
 }
 
+const HANGUL_SYLLABLE_FIRST: u32 = 44032;
+const HANGUL_SYLLABLE_LAST: u32 = HANGUL_SYLLABLE_FIRST + 11171;
+
 // the formula comes from this Wikipedia page:
 // https://en.wikipedia.org/wiki/Korean_language_and_computers#Hangul_Syllables_block
-fn calculate_syllable_u32(initial_consonant: u32, medial_vowel: u32, final_consonant: u32) -> char {
+pub(super) fn calculate_syllable_u32(initial_consonant: u32, medial_vowel: u32, final_consonant: u32) -> char {
     unsafe {
         std::char::from_u32_unchecked(
-            initial_consonant * 588 + medial_vowel * 28 + final_consonant + 44032,
+            initial_consonant * 588 + medial_vowel * 28 + final_consonant + HANGUL_SYLLABLE_FIRST,
         )
     }
 }
 
-impl From<Byte> for Syllable {
-    fn from(b: Byte) -> Self {
-        if b.is_consonant() {
-            Self::Initial(InitialConsonant::try_from(b).unwrap())
-        } else {
-            Self::VowelOnly(MedialVowel::try_from(b).unwrap())
+impl From<Jamo> for Syllable {
+    fn from(jamo: Jamo) -> Self {
+        match jamo {
+            Jamo::Consonant(ic) | Jamo::Initial(ic) => Self::Initial(ic),
+            Jamo::Vowel(mv) | Jamo::Medial(mv) => Self::VowelOnly(mv),
+            Jamo::Final(fc) => Self::FinalOnly(fc),
         }
     }
 }
@@ -145,10 +384,10 @@ mod tests {
 
     #[test]
     fn test_into_char() {
-        let mut syllable1 = Syllable::from(Byte::M);
-        syllable1.put(Byte::A);
-        syllable1.put(Byte::N);
-        let char1: char = syllable1.into();
+        let mut syllable1 = Syllable::from(Jamo::from(Byte::M));
+        syllable1.put(Byte::A.into(), Rules::default());
+        syllable1.put(Byte::N.into(), Rules::default());
+        let char1 = char::try_from(syllable1).unwrap();
         assert_eq!(char1, '만');
     }
 
@@ -190,8 +429,84 @@ mod tests {
             (Byte::K, 'ㅋ'),
         ];
         for pair in pairs {
-            let c: char = Syllable::from(pair.0).into();
+            let c = char::try_from(Syllable::from(Jamo::from(pair.0))).unwrap();
             assert_eq!(c, pair.1)
         }
     }
+
+    #[test]
+    fn test_compound_jamo() {
+        let rules = Rules {
+            compound_jamo: true,
+            ..Rules::default()
+        };
+        let mut consonant = Syllable::from(Jamo::from(Byte::G));
+        assert!(consonant.put(Byte::S.into(), rules).is_none());
+        assert_eq!(char::try_from(consonant).unwrap(), 'ㄳ');
+        assert!(consonant.put(Byte::S.into(), rules).is_some());
+        let splitted = consonant.try_split_with_vowel(Byte::A.into()).unwrap();
+        assert_eq!(char::try_from(consonant).unwrap(), 'ㄱ');
+        assert_eq!(char::try_from(splitted).unwrap(), '사');
+
+        let mut vowel = Syllable::from(Jamo::from(Byte::O));
+        assert!(vowel.put(Byte::A.into(), Rules::default()).is_some());
+        assert!(vowel.put(Byte::A.into(), rules).is_none());
+        assert_eq!(char::try_from(vowel).unwrap(), 'ㅘ');
+        assert!(vowel.remove_last().is_some());
+        assert_eq!(char::try_from(vowel).unwrap(), 'ㅗ');
+
+        let mut consonant = Syllable::from(Jamo::from(Byte::R));
+        assert!(consonant.put(Byte::R.into(), rules).is_some());
+        assert!(consonant.put(Byte::H.into(), rules).is_none());
+        assert_eq!(char::try_from(consonant).unwrap(), 'ㅀ');
+        assert!(consonant.remove_last().is_some());
+        assert!(consonant.put(Byte::A.into(), rules).is_none());
+        assert_eq!(char::try_from(consonant).unwrap(), '라');
+    }
+
+    #[test]
+    fn test_double_tap() {
+        let rules = Rules {
+            double_tap: true,
+            ..Rules::default()
+        };
+        let mut syllable = Syllable::from(Jamo::from(Byte::J));
+        assert!(syllable.put(Byte::J.into(), Rules::default()).is_some());
+        assert!(syllable.put(Byte::J.into(), rules).is_none());
+        assert_eq!(char::try_from(syllable).unwrap(), 'ㅉ');
+        assert!(syllable.put(Byte::J.into(), rules).is_some());
+        syllable.put(Byte::A.into(), rules);
+        assert!(syllable.put(Byte::G.into(), rules).is_none());
+        assert!(syllable.put(Byte::G.into(), rules).is_none());
+        assert_eq!(char::try_from(syllable).unwrap(), '짞');
+
+        let mut syllable = Syllable::from(Jamo::from(Byte::B));
+        syllable.put(Byte::A.into(), rules);
+        assert!(syllable.put(Byte::B.into(), rules).is_none());
+        assert!(syllable.put(Byte::B.into(), rules).is_some());
+        assert!(syllable.put(Byte::S.into(), rules).is_none());
+        assert_eq!(char::try_from(syllable).unwrap(), '밦');
+    }
+
+    #[test]
+    fn test_moachigi() {
+        let rules = Rules {
+            moachigi: true,
+            ..Rules::default()
+        };
+        let mut syllable = Syllable::from(Jamo::from(Byte::A));
+        assert!(syllable.put(Byte::G.into(), Rules::default()).is_some());
+        assert!(syllable.put(Byte::G.into(), rules).is_none());
+        assert!(syllable.put(Byte::N.into(), rules).is_none());
+        assert_eq!(char::try_from(syllable).unwrap(), '간');
+
+        let mut syllable = Syllable::from(Jamo::Medial(MedialVowel::WA));
+        assert!(syllable
+            .put(Jamo::Final(FinalConsonant::N), rules)
+            .is_some());
+        assert!(syllable
+            .put(Jamo::Initial(InitialConsonant::G), rules)
+            .is_none());
+        assert_eq!(char::try_from(syllable).unwrap(), '과');
+    }
 }
@@ -0,0 +1,42 @@
+// Fixtures shared by the tests of several modules.
+use crate::{Buffer, Layout, RomajaBuffer};
+
+// Every modern Hangul syllable, from '가' to '힣'.
+pub(crate) fn all_syllables() -> impl Iterator<Item = char> {
+    (0xac00..=0xd7a3).map(|code| std::char::from_u32(code).unwrap())
+}
+
+// A buffer that keys can be typed into.
+pub(crate) trait Keys {
+    fn put(&mut self, key: char) -> Option<char>;
+    fn out(&mut self) -> String;
+}
+
+impl<L: Layout> Keys for Buffer<L> {
+    fn put(&mut self, key: char) -> Option<char> {
+        Buffer::put(self, key)
+    }
+
+    fn out(&mut self) -> String {
+        Buffer::out(self)
+    }
+}
+
+impl Keys for RomajaBuffer {
+    fn put(&mut self, key: char) -> Option<char> {
+        RomajaBuffer::put(self, key)
+    }
+
+    fn out(&mut self) -> String {
+        RomajaBuffer::out(self)
+    }
+}
+
+// Puts every key into the buffer, each of which must be accepted, and takes
+// out what they typed.
+pub(crate) fn type_keys(mut buffer: impl Keys, keys: &str) -> String {
+    for key in keys.chars() {
+        assert!(buffer.put(key).is_none(), "{} is rejected", key);
+    }
+    buffer.out()
+}